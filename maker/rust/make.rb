@@ -43,16 +43,21 @@ def calc_refs(name, properties, refs)
       elsif value[:properties].present?
         calc_refs(key, value[:properties], refs)
       end
-    elsif value[:type] == "array" && value[:items][:type] == "object" && value[:items][:ref] != name.to_s
-      refs << value[:items][:ref]
+    elsif value[:type] == "array" && value[:items][:type] == "object"
+      if value[:items][:ref].present? && value[:items][:ref] != name.to_s
+        refs << value[:items][:ref]
+      elsif value[:items][:properties].present?
+        calc_refs(key, value[:items][:properties], refs)
+      end
     end
   end
 end
 
-def make_response(name, properties, independence_flag)
+def make_response(name, properties, independence_flag, field_flag = independence_flag)
   return ["", [], {}] if properties.blank?
   refs = []
   @enums = {}
+  @others = []
   @inner_map = {}
   calc_refs(name, properties, refs)
   refs.uniq!
@@ -61,15 +66,26 @@ def make_response(name, properties, independence_flag)
   [erb.result(binding), refs, @inner_map]
 end
 
+# 入れ子のオブジェクトも順に展開する
+def inner_responses(map)
+  results = []
+  map.deep_dup.each_pair do |key, value|
+    responses, _, inner = make_response(key.to_s, value.dig(:properties), false)
+    results << responses
+    results.concat(inner_responses(inner))
+  end
+  results
+end
+
 def execute_responses(path)
   m = /responses\/(.+)\.yaml/.match(path)
   name = m[1]
   yml = YAML.load_file(path).deep_symbolize_keys
   properties = yml[:response][:properties]
   independence_flag = true
-  res, refs, map = make_response(name, properties, independence_flag)
-  map.deep_dup.each_pair do |key, value|
-    responses, _, _ = make_response(key.to_s, value.dig(:properties), false)
+  field_flag = yml[:response][:no_field].blank?
+  res, refs, map = make_response(name, properties, independence_flag, field_flag)
+  inner_responses(map).each do |responses|
     res = res + "\n" + responses
   end
 
@@ -80,12 +96,7 @@ def response_list(yml)
   response_list = []
   responses, refs, map = make_response("response", yml.dig(:response, :properties), false)
   response_list << responses if responses.present?
-  if map.present?
-    map.each_pair do |key, value|
-      responses, _, _ = make_response(key.to_s, value.dig(:properties), false)
-      response_list << responses
-    end
-  end
+  response_list.concat(inner_responses(map))
   [response_list, refs]
 end
 
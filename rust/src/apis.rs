@@ -1,17 +1,116 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::{error::Error, options::TiktokOptions};
 use chrono::prelude::*;
 use reqwest::{RequestBuilder, StatusCode, header::HeaderMap};
+use reqwest_builder_retry::RetryType;
 use serde::de::DeserializeOwned;
 
+pub mod get_ad_get;
+pub mod get_adgroup_get;
+pub mod get_advertiser_balance_get;
+pub mod get_advertiser_info;
+pub mod get_bc_asset_get;
+pub mod get_bc_get;
+pub mod get_bc_member_get;
+pub mod get_blockedword_list;
+pub mod get_business_benchmark;
 pub mod get_business_comment_list;
 pub mod get_business_get;
+pub mod get_business_mention_comment_list;
+pub mod get_business_mention_video_get;
+pub mod get_business_mention_video_list;
 pub mod get_business_publish_status;
 pub mod get_business_video_list;
+#[cfg(feature = "webhook")]
+pub mod get_business_webhook_list;
+pub mod get_campaign_get;
+pub mod get_catalog_feed_get;
+pub mod get_catalog_get;
+pub mod get_catalog_product_get;
+pub mod get_catalog_product_log;
+pub mod get_comment_list;
+pub mod get_dmp_custom_audience_get;
+pub mod get_dmp_custom_audience_list;
+pub mod get_identity_get;
+pub mod get_identity_info;
+pub mod get_identity_video_get;
+pub mod get_oembed;
+pub mod get_optimizer_rule_list;
+pub mod get_optimizer_rule_result_list;
+pub mod get_page_get;
+pub mod get_page_lead_task;
+pub mod get_page_lead_task_download;
+pub mod get_report_integrated_get;
+pub mod get_report_task_check;
+pub mod get_report_task_download;
+pub mod get_tool_device_model;
+pub mod get_tool_interest_category;
+pub mod get_tool_language;
+pub mod get_tool_os_version;
+pub mod get_tool_region;
+pub mod get_tt_video_info;
+pub mod get_tt_video_list;
+pub mod get_v2_user_info;
+pub mod post_ad_create;
+pub mod post_ad_status_update;
+pub mod post_ad_update;
+pub mod post_adgroup_create;
+pub mod post_adgroup_status_update;
+pub mod post_adgroup_update;
+pub mod post_blockedword_create;
+pub mod post_blockedword_delete;
 pub mod post_business_comment_reply_create;
 pub mod post_business_photo_publish;
+pub mod post_business_video_auth_code_generate;
 pub mod post_business_video_publish;
+pub mod post_business_video_settings_update;
+#[cfg(feature = "webhook")]
+pub mod post_business_webhook_delete;
+#[cfg(feature = "webhook")]
+pub mod post_business_webhook_update;
+pub mod post_campaign_create;
+pub mod post_campaign_status_update;
+pub mod post_campaign_update;
+pub mod post_catalog_create;
+pub mod post_catalog_feed_create;
+pub mod post_catalog_feed_update;
+pub mod post_catalog_product_file;
+pub mod post_catalog_product_upload;
+pub mod post_comment_post;
+pub mod post_comment_status_update;
+pub mod post_dmp_custom_audience_create;
+pub mod post_dmp_custom_audience_delete;
+pub mod post_dmp_custom_audience_file_upload;
+pub mod post_dmp_custom_audience_update;
+#[cfg(feature = "events")]
+pub mod post_event_track;
+pub mod post_file_image_ad_upload;
+pub mod post_file_video_ad_upload;
+pub mod post_identity_create;
+pub mod post_optimizer_rule_create;
+pub mod post_optimizer_rule_update;
+pub mod post_optimizer_rule_update_status;
+pub mod post_page_lead_task;
+pub mod post_report_task_create;
+pub mod post_tt_video_authorize;
+pub mod post_v2_post_publish_inbox_video_init;
+pub mod post_v2_post_publish_status_fetch;
+pub mod post_v2_post_publish_video_init;
+pub mod post_v2_research_adlib_ad_detail;
+pub mod post_v2_research_adlib_ad_query;
+pub mod post_v2_research_adlib_advertiser_query;
+pub mod post_v2_research_adlib_commercial_content_query;
+pub mod post_v2_research_user_followers;
+pub mod post_v2_research_user_following;
+pub mod post_v2_research_user_info;
+pub mod post_v2_research_user_liked_videos;
+pub mod post_v2_research_user_pinned_videos;
+pub mod post_v2_research_user_reposted_videos;
+pub mod post_v2_research_video_comment_list;
+pub mod post_v2_research_video_query;
+pub mod post_v2_video_list;
+pub mod post_v2_video_query;
 
 #[derive(Debug)]
 pub struct ApiResponse<T> {
@@ -69,6 +168,76 @@ where
     }
 }
 
+pub(crate) async fn execute_raw(
+    f: impl Fn() -> RequestBuilder,
+    options: &Option<TiktokOptions>,
+) -> Result<reqwest::Response, Error> {
+    let res = reqwest_builder_retry::convenience::execute(
+        |_| f(),
+        |response| async move {
+            let response = response.map_err(|err| (RetryType::Retry, Error::Reqwest(err)))?;
+            let status_code = response.status();
+            match reqwest_builder_retry::convenience::check_status_code(
+                status_code,
+                &[
+                    StatusCode::TOO_MANY_REQUESTS,
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ],
+            )
+            .await
+            {
+                None => Ok(response),
+                Some(retry_type) => Err((
+                    retry_type,
+                    Error::Other(response.text().await.unwrap_or_default(), status_code),
+                )),
+            }
+        },
+        options.as_ref().and_then(|opt| opt.try_count).unwrap_or(0), // リトライ回数
+        options
+            .as_ref()
+            .and_then(|opt| opt.retry_duration)
+            .unwrap_or(Duration::from_secs(2)), // リトライ間隔
+    )
+    .await;
+
+    match res {
+        Ok(response) => Ok(response),
+        Err(err) => match err {
+            reqwest_builder_retry::error::Error::NoTry => Err(Error::Invalid("NoTry".into())),
+            reqwest_builder_retry::error::Error::Stop(err) => Err(err),
+            reqwest_builder_retry::error::Error::TryOver(err) => Err(err),
+        },
+    }
+}
+
+pub(crate) async fn poll_api<T, F, Fut>(f: F, options: &Option<TiktokOptions>) -> Result<T, Error>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<Option<T>, Error>>,
+{
+    let started_at = Instant::now();
+    let timeout = options
+        .as_ref()
+        .and_then(|opt| opt.poll_timeout_duration)
+        .unwrap_or(Duration::from_secs(30 * 60)); // ポーリング全体の上限
+    let mut interval = options
+        .as_ref()
+        .and_then(|opt| opt.retry_duration)
+        .unwrap_or(Duration::from_secs(2)); // 初回のポーリング間隔
+    loop {
+        if let Some(result) = f().await? {
+            return Ok(result);
+        }
+        if started_at.elapsed() + interval > timeout {
+            return Err(Error::Timeout);
+        }
+        tokio::time::sleep(interval).await;
+        // exponential backoff
+        interval = (interval * 2).min(Duration::from_secs(60));
+    }
+}
+
 fn convert_response_error(err: reqwest_builder_retry::convenience::json::ResponseError) -> Error {
     if let Some(response_data) = err.response_data {
         Error::Other(response_data.body, response_data.status_code)
@@ -0,0 +1,105 @@
+use crate::responses::advertiser_info::AdvertiserInfo;
+use crate::responses::advertiser_info::AdvertiserInfoField;
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use itertools::Itertools;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const URL: &str = "/advertiser/info/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_ids: Vec<String>,
+    fields: Option<HashSet<AdvertiserInfoField>>,
+}
+
+impl Api {
+    pub fn new(advertiser_ids: Vec<String>, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            advertiser_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn fields(mut self, value: HashSet<AdvertiserInfoField>) -> Self {
+        self.fields = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push((
+            "advertiser_ids",
+            format!("[\"{}\"]", self.advertiser_ids.join("\",\"")),
+        ));
+        if let Some(fields) = self.fields {
+            query_parameters.push(("fields", format!("[\"{}\"]", fields.iter().join("\",\""))));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list: Option<Vec<AdvertiserInfo>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .list
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,190 @@
+use crate::responses::{bc_asset::BcAsset, page_info::PageInfo};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/bc/asset/get/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    bc_id: String,
+    asset_type: AssetType,
+    filtering: Option<Filtering>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum AssetType {
+    #[serde(rename = "ADVERTISER")]
+    #[default]
+    Advertiser,
+    #[serde(rename = "CATALOG")]
+    Catalog,
+    #[serde(rename = "TIKTOK_ACCOUNT")]
+    TiktokAccount,
+    #[serde(rename = "PIXEL")]
+    Pixel,
+}
+
+impl std::fmt::Display for AssetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Advertiser => write!(f, "ADVERTISER"),
+            Self::Catalog => write!(f, "CATALOG"),
+            Self::TiktokAccount => write!(f, "TIKTOK_ACCOUNT"),
+            Self::Pixel => write!(f, "PIXEL"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Filtering {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_ids: Option<Vec<String>>,
+}
+
+impl std::fmt::Display for Filtering {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", serde_json::to_string(&self).unwrap())
+    }
+}
+
+impl Api {
+    pub fn new(bc_id: &str, asset_type: AssetType, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            bc_id: bc_id.to_owned(),
+            asset_type,
+            ..Default::default()
+        }
+    }
+
+    pub fn filtering(mut self, value: Filtering) -> Self {
+        self.filtering = Some(value);
+        self
+    }
+
+    pub fn page(mut self, value: usize) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    pub fn page_size(mut self, value: usize) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("bc_id", self.bc_id));
+        query_parameters.push(("asset_type", self.asset_type.to_string()));
+        if let Some(filtering) = self.filtering {
+            query_parameters.push(("filtering", filtering.to_string()));
+        }
+        if let Some(page) = self.page {
+            query_parameters.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query_parameters.push(("page_size", page_size.to_string()));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    pub async fn execute_all(self, bearer_code: &str) -> Result<Vec<BcAsset>, ApiError> {
+        let mut assets = vec![];
+        let mut page = self.page.unwrap_or(1);
+        loop {
+            let res = self.clone().page(page).execute(bearer_code).await?;
+            if res.body.code != 0 {
+                return Err(ApiError::Api(
+                    res.body.code,
+                    res.body.message,
+                    res.status_code,
+                ));
+            }
+            let Some(data) = res.body.data else {
+                break;
+            };
+            assets.extend(data.list.unwrap_or_default());
+            let total_page = data.page_info.and_then(|it| it.total_page).unwrap_or(0);
+            if page as i64 >= total_page {
+                break;
+            }
+            page += 1;
+        }
+        Ok(assets)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list: Option<Vec<BcAsset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<PageInfo>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .list
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .page_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
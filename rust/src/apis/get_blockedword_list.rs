@@ -0,0 +1,156 @@
+use crate::responses::{blocked_word::BlockedWord, page_info::PageInfo};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+const URL: &str = "/blockedword/list/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    word: Option<String>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+}
+
+impl Api {
+    pub fn new(advertiser_id: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn word(mut self, value: &str) -> Self {
+        self.word = Some(value.to_owned());
+        self
+    }
+
+    pub fn page(mut self, value: usize) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    pub fn page_size(mut self, value: usize) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        if let Some(word) = self.word {
+            query_parameters.push(("word", word));
+        }
+        if let Some(page) = self.page {
+            query_parameters.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query_parameters.push(("page_size", page_size.to_string()));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // 全ページを取得して単語の集合で返す
+    pub async fn execute_all(self, bearer_code: &str) -> Result<BTreeSet<String>, ApiError> {
+        let mut words = BTreeSet::new();
+        let mut page = self.page.unwrap_or(1);
+        loop {
+            let res = self.clone().page(page).execute(bearer_code).await?;
+            if res.body.code != 0 {
+                return Err(ApiError::Api(
+                    res.body.code,
+                    res.body.message,
+                    res.status_code,
+                ));
+            }
+            let Some(data) = res.body.data else {
+                break;
+            };
+            words.extend(
+                data.blocked_words
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|it| it.word),
+            );
+            let total_page = data.page_info.and_then(|it| it.total_page).unwrap_or(0);
+            if page as i64 >= total_page {
+                break;
+            }
+            page += 1;
+        }
+        Ok(words)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_words: Option<Vec<BlockedWord>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<PageInfo>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .blocked_words
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .page_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
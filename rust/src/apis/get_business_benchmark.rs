@@ -0,0 +1,212 @@
+use crate::responses::benchmark::Benchmark;
+use crate::responses::benchmark::BenchmarkField;
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use itertools::Itertools;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const URL: &str = "/business/benchmark/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    business_id: String,
+    business_category: BusinessCategory,
+    fields: HashSet<BenchmarkField>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum BusinessCategory {
+    #[serde(rename = "ART_AND_CRAFTS")]
+    #[default]
+    ArtAndCrafts,
+    #[serde(rename = "AUTOMOTIVE_AND_TRANSPORTATION")]
+    AutomotiveAndTransportation,
+    #[serde(rename = "BABY")]
+    Baby,
+    #[serde(rename = "BEAUTY")]
+    Beauty,
+    #[serde(rename = "CLOTHING_AND_ACCESSORIES")]
+    ClothingAndAccessories,
+    #[serde(rename = "EDUCATION_AND_TRAINING")]
+    EducationAndTraining,
+    #[serde(rename = "ELECTRONICS")]
+    Electronics,
+    #[serde(rename = "FINANCE_AND_INVESTING")]
+    FinanceAndInvesting,
+    #[serde(rename = "FOOD_AND_BEVERAGE")]
+    FoodAndBeverage,
+    #[serde(rename = "GAMING")]
+    Gaming,
+    #[serde(rename = "HEALTH_AND_WELLNESS")]
+    HealthAndWellness,
+    #[serde(rename = "HOME_FURNITURE_AND_APPLIANCES")]
+    HomeFurnitureAndAppliances,
+    #[serde(rename = "MACHINERY_AND_EQUIPMENT")]
+    MachineryAndEquipment,
+    #[serde(rename = "MEDIA_AND_ENTERTAINMENT")]
+    MediaAndEntertainment,
+    #[serde(rename = "PERSONAL_BLOG")]
+    PersonalBlog,
+    #[serde(rename = "PETS")]
+    Pets,
+    #[serde(rename = "PROFESSIONAL_SERVICES")]
+    ProfessionalServices,
+    #[serde(rename = "PUBLIC_ADMINISTRATION")]
+    PublicAdministration,
+    #[serde(rename = "REAL_ESTATE")]
+    RealEstate,
+    #[serde(rename = "RESTAURANTS_AND_BARS")]
+    RestaurantsAndBars,
+    #[serde(rename = "SHOPPING_AND_RETAIL")]
+    ShoppingAndRetail,
+    #[serde(rename = "SOFTWARE_AND_APPS")]
+    SoftwareAndApps,
+    #[serde(rename = "SPORTS_FITNESS_AND_OUTDOORS")]
+    SportsFitnessAndOutdoors,
+    #[serde(rename = "TRAVEL_AND_TOURISM")]
+    TravelAndTourism,
+    #[serde(rename = "OTHERS")]
+    Others,
+}
+
+impl std::fmt::Display for BusinessCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ArtAndCrafts => write!(f, "ART_AND_CRAFTS"),
+            Self::AutomotiveAndTransportation => write!(f, "AUTOMOTIVE_AND_TRANSPORTATION"),
+            Self::Baby => write!(f, "BABY"),
+            Self::Beauty => write!(f, "BEAUTY"),
+            Self::ClothingAndAccessories => write!(f, "CLOTHING_AND_ACCESSORIES"),
+            Self::EducationAndTraining => write!(f, "EDUCATION_AND_TRAINING"),
+            Self::Electronics => write!(f, "ELECTRONICS"),
+            Self::FinanceAndInvesting => write!(f, "FINANCE_AND_INVESTING"),
+            Self::FoodAndBeverage => write!(f, "FOOD_AND_BEVERAGE"),
+            Self::Gaming => write!(f, "GAMING"),
+            Self::HealthAndWellness => write!(f, "HEALTH_AND_WELLNESS"),
+            Self::HomeFurnitureAndAppliances => write!(f, "HOME_FURNITURE_AND_APPLIANCES"),
+            Self::MachineryAndEquipment => write!(f, "MACHINERY_AND_EQUIPMENT"),
+            Self::MediaAndEntertainment => write!(f, "MEDIA_AND_ENTERTAINMENT"),
+            Self::PersonalBlog => write!(f, "PERSONAL_BLOG"),
+            Self::Pets => write!(f, "PETS"),
+            Self::ProfessionalServices => write!(f, "PROFESSIONAL_SERVICES"),
+            Self::PublicAdministration => write!(f, "PUBLIC_ADMINISTRATION"),
+            Self::RealEstate => write!(f, "REAL_ESTATE"),
+            Self::RestaurantsAndBars => write!(f, "RESTAURANTS_AND_BARS"),
+            Self::ShoppingAndRetail => write!(f, "SHOPPING_AND_RETAIL"),
+            Self::SoftwareAndApps => write!(f, "SOFTWARE_AND_APPS"),
+            Self::SportsFitnessAndOutdoors => write!(f, "SPORTS_FITNESS_AND_OUTDOORS"),
+            Self::TravelAndTourism => write!(f, "TRAVEL_AND_TOURISM"),
+            Self::Others => write!(f, "OTHERS"),
+        }
+    }
+}
+
+impl Api {
+    pub fn new(
+        business_id: &str,
+        business_category: BusinessCategory,
+        fields: HashSet<BenchmarkField>,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            business_id: business_id.to_owned(),
+            business_category,
+            fields,
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("business_id", self.business_id));
+        query_parameters.push(("business_category", self.business_category.to_string()));
+        query_parameters.push((
+            "fields",
+            format!("[\"{}\"]", self.fields.iter().join("\",\"")),
+        ));
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Benchmark>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::benchmark::BusinessCategory as ResponseBusinessCategory;
+
+    // cargo test test_business_benchmark_business_category -- --nocapture --test-threads=1
+    #[test]
+    fn test_business_benchmark_business_category() -> anyhow::Result<()> {
+        let res: Response = serde_json::from_str(
+            r#"{
+                "request_id": "1",
+                "code": 0,
+                "message": "OK",
+                "data": {"business_category": "GAMING", "followers_count": 10}
+            }"#,
+        )?;
+        assert!(res.is_empty_extra());
+        let category = res.data.and_then(|it| it.business_category);
+        assert!(matches!(category, Some(ResponseBusinessCategory::Gaming)));
+        assert_eq!(
+            category.map(|it| it.to_string()),
+            Some(BusinessCategory::Gaming.to_string())
+        );
+
+        // 未知の値は Unknown に入れて失敗させない
+        let res: Response = serde_json::from_str(
+            r#"{
+                "request_id": "1",
+                "code": 0,
+                "message": "OK",
+                "data": {"business_category": "NEW_CATEGORY"}
+            }"#,
+        )?;
+        let category = res.data.and_then(|it| it.business_category);
+        assert!(
+            matches!(&category, Some(ResponseBusinessCategory::Unknown(value)) if value == "NEW_CATEGORY")
+        );
+        assert_eq!(serde_json::to_string(&category)?, r#""NEW_CATEGORY""#);
+        Ok(())
+    }
+}
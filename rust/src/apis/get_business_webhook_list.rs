@@ -0,0 +1,102 @@
+use crate::responses::webhook_subscription::WebhookSubscription;
+use crate::webhook::EventType;
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/business/webhook/list/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    app_id: String,
+    secret: String,
+    event_type: Option<EventType>,
+}
+
+impl Api {
+    pub fn new(app_id: &str, secret: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            app_id: app_id.to_owned(),
+            secret: secret.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn event_type(mut self, value: EventType) -> Self {
+        self.event_type = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("app_id", self.app_id));
+        query_parameters.push(("secret", self.secret));
+        if let Some(event_type) = self.event_type {
+            query_parameters.push(("event_type", event_type.to_string()));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhooks: Option<Vec<WebhookSubscription>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .webhooks
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
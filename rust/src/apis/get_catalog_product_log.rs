@@ -0,0 +1,178 @@
+use crate::{
+    apis::{ApiResponse, execute_api, poll_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/catalog/product/log/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    bc_id: String,
+    catalog_id: String,
+    feed_log_id: String,
+}
+
+impl Api {
+    pub fn new(
+        bc_id: &str,
+        catalog_id: &str,
+        feed_log_id: &str,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            bc_id: bc_id.to_owned(),
+            catalog_id: catalog_id.to_owned(),
+            feed_log_id: feed_log_id.to_owned(),
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("bc_id", self.bc_id));
+        query_parameters.push(("catalog_id", self.catalog_id));
+        query_parameters.push(("feed_log_id", self.feed_log_id));
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // FAILEDでもSKUごとのエラーを見られるようにDataを返す
+    pub async fn execute_until_done(self, bearer_code: &str) -> Result<Data, ApiError> {
+        let api = &self;
+        poll_api(
+            || async move {
+                let res = api.clone().execute(bearer_code).await?;
+                if res.body.code != 0 {
+                    return Err(ApiError::Api(
+                        res.body.code,
+                        res.body.message,
+                        res.status_code,
+                    ));
+                }
+                let Some(data) = res.body.data else {
+                    return Ok(None);
+                };
+                match data.status {
+                    Some(Status::Success) | Some(Status::Failed) => Ok(Some(data)),
+                    _ => Ok(None),
+                }
+            },
+            &self.options,
+        )
+        .await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_list: Option<Vec<ProductError>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .error_list
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Status {
+    #[serde(rename = "PROCESSING")]
+    #[default]
+    Processing,
+    #[serde(rename = "SUCCESS")]
+    Success,
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Processing => write!(f, "PROCESSING"),
+            Self::Success => write!(f, "SUCCESS"),
+            Self::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProductError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ProductError {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ProductError {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,181 @@
+use crate::responses::comment::CommentType;
+use crate::responses::comment::Status;
+use crate::responses::{comment::Comment, page_info::PageInfo};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/comment/list/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    search_field: SearchField,
+    search_value: String,
+    start_time: String,
+    end_time: String,
+    comment_status: Option<Vec<Status>>,
+    comment_type: Option<Vec<CommentType>>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum SearchField {
+    #[serde(rename = "ADGROUP_ID")]
+    #[default]
+    AdgroupId,
+    #[serde(rename = "AD_ID")]
+    AdId,
+    #[serde(rename = "CAMPAIGN_ID")]
+    CampaignId,
+}
+
+impl std::fmt::Display for SearchField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AdgroupId => write!(f, "ADGROUP_ID"),
+            Self::AdId => write!(f, "AD_ID"),
+            Self::CampaignId => write!(f, "CAMPAIGN_ID"),
+        }
+    }
+}
+
+impl Api {
+    pub fn new(
+        advertiser_id: &str,
+        search_field: SearchField,
+        search_value: &str,
+        start_time: &str,
+        end_time: &str,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            search_field,
+            search_value: search_value.to_owned(),
+            start_time: start_time.to_owned(),
+            end_time: end_time.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn comment_status(mut self, value: Vec<Status>) -> Self {
+        self.comment_status = Some(value);
+        self
+    }
+
+    pub fn comment_type(mut self, value: Vec<CommentType>) -> Self {
+        self.comment_type = Some(value);
+        self
+    }
+
+    pub fn page(mut self, value: usize) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    pub fn page_size(mut self, value: usize) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        query_parameters.push(("search_field", self.search_field.to_string()));
+        query_parameters.push(("search_value", self.search_value));
+        query_parameters.push(("start_time", self.start_time));
+        query_parameters.push(("end_time", self.end_time));
+        if let Some(comment_status) = self.comment_status {
+            query_parameters.push((
+                "comment_status",
+                serde_json::to_string(&comment_status).unwrap(),
+            ));
+        }
+        if let Some(comment_type) = self.comment_type {
+            query_parameters.push((
+                "comment_type",
+                serde_json::to_string(&comment_type).unwrap(),
+            ));
+        }
+        if let Some(page) = self.page {
+            query_parameters.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query_parameters.push(("page_size", page_size.to_string()));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Vec<Comment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<PageInfo>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .comments
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .page_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
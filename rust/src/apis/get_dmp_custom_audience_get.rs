@@ -0,0 +1,308 @@
+use crate::responses::custom_audience::{AudienceStatus, CustomAudience};
+use crate::{
+    apis::{ApiResponse, execute_api, poll_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/dmp/custom_audience/get/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    custom_audience_ids: Vec<String>,
+    history_size: Option<usize>,
+}
+
+impl Api {
+    pub fn new(
+        advertiser_id: &str,
+        custom_audience_ids: Vec<String>,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            custom_audience_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn history_size(mut self, value: usize) -> Self {
+        self.history_size = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        query_parameters.push((
+            "custom_audience_ids",
+            format!("[\"{}\"]", self.custom_audience_ids.join("\",\"")),
+        ));
+        if let Some(history_size) = self.history_size {
+            query_parameters.push(("history_size", history_size.to_string()));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // 指定した全てのオーディエンスが利用可能になるまで待つ
+    pub async fn execute_until_ready(
+        self,
+        bearer_code: &str,
+    ) -> Result<Vec<CustomAudience>, ApiError> {
+        if self.custom_audience_ids.is_empty() {
+            return Err(ApiError::Invalid("custom_audience_ids empty".to_owned()));
+        }
+        let api = &self;
+        poll_api(
+            || async move {
+                let res = api.clone().execute(bearer_code).await?;
+                if res.body.code != 0 {
+                    return Err(ApiError::Api(
+                        res.body.code,
+                        res.body.message,
+                        res.status_code,
+                    ));
+                }
+                let audiences: Vec<CustomAudience> = res
+                    .body
+                    .data
+                    .and_then(|it| it.list)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|it| it.audience_details)
+                    .collect();
+                ready_audiences(&api.custom_audience_ids, audiences)
+            },
+            &self.options,
+        )
+        .await
+    }
+}
+
+// 失敗や未知のステータスはタイムアウトを待たずにエラーにする
+// レスポンスに含まれないIDがあれば、まだ準備できていないとみなす
+fn ready_audiences(
+    custom_audience_ids: &[String],
+    audiences: Vec<CustomAudience>,
+) -> Result<Option<Vec<CustomAudience>>, ApiError> {
+    if let Some(failed) = audiences
+        .iter()
+        .find(|it| is_failed(it) || matches!(audience_status(it), AudienceStatus::Unknown(_)))
+    {
+        return Err(ApiError::Invalid(format!(
+            "custom audience {} {}",
+            failed.audience_id.as_deref().unwrap_or_default(),
+            audience_status(failed)
+        )));
+    }
+    let ready = custom_audience_ids.iter().all(|id| {
+        audiences.iter().any(|it| {
+            it.audience_id.as_deref() == Some(id.as_str())
+                && matches!(audience_status(it), AudienceStatus::Valid)
+        })
+    });
+    if !ready {
+        return Ok(None);
+    }
+    Ok(Some(audiences))
+}
+
+// status が無い場合は is_valid から判断する
+fn audience_status(audience: &CustomAudience) -> AudienceStatus {
+    match (audience.status.clone(), audience.is_valid) {
+        (Some(status), _) => status,
+        (None, Some(true)) => AudienceStatus::Valid,
+        (None, _) => AudienceStatus::Calculating,
+    }
+}
+
+fn is_failed(audience: &CustomAudience) -> bool {
+    matches!(
+        audience_status(audience),
+        AudienceStatus::Invalid | AudienceStatus::Expired | AudienceStatus::Failed
+    )
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list: Option<Vec<List>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .list
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct List {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience_details: Option<CustomAudience>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience_history: Option<Vec<AudienceHistory>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl List {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .audience_details
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .audience_history
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("List {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AudienceHistory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opt_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msg: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AudienceHistory {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("AudienceHistory {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_custom_audience_status -- --nocapture --test-threads=1
+    #[test]
+    fn test_custom_audience_status() -> anyhow::Result<()> {
+        let audience: CustomAudience =
+            serde_json::from_str(r#"{"audience_id":"1","status":"FAILED","is_valid":false}"#)?;
+        assert!(matches!(audience_status(&audience), AudienceStatus::Failed));
+        assert!(is_failed(&audience));
+
+        let audience: CustomAudience =
+            serde_json::from_str(r#"{"audience_id":"2","status":"SOMETHING_NEW"}"#)?;
+        assert!(
+            matches!(audience_status(&audience), AudienceStatus::Unknown(status) if status == "SOMETHING_NEW")
+        );
+        assert!(!is_failed(&audience));
+        assert_eq!(serde_json::to_value(&audience.status)?, "SOMETHING_NEW");
+
+        let audience: CustomAudience = serde_json::from_str(r#"{"is_valid":true}"#)?;
+        assert!(matches!(audience_status(&audience), AudienceStatus::Valid));
+        let audience: CustomAudience = serde_json::from_str(r#"{"is_valid":false}"#)?;
+        assert!(matches!(
+            audience_status(&audience),
+            AudienceStatus::Calculating
+        ));
+        assert!(!is_failed(&audience));
+        Ok(())
+    }
+
+    // cargo test test_custom_audience_ready -- --nocapture --test-threads=1
+    #[test]
+    fn test_custom_audience_ready() -> anyhow::Result<()> {
+        let ids = vec!["1".to_owned(), "2".to_owned()];
+        let audience = |json: &str| serde_json::from_str::<CustomAudience>(json);
+
+        // 2 がまだレスポンスに含まれない
+        let audiences = vec![audience(r#"{"audience_id":"1","status":"VALID"}"#)?];
+        assert!(ready_audiences(&ids, audiences)?.is_none());
+
+        let audiences = vec![
+            audience(r#"{"audience_id":"1","status":"VALID"}"#)?,
+            audience(r#"{"audience_id":"2","status":"CALCULATING"}"#)?,
+        ];
+        assert!(ready_audiences(&ids, audiences)?.is_none());
+
+        let audiences = vec![
+            audience(r#"{"audience_id":"2","status":"VALID"}"#)?,
+            audience(r#"{"audience_id":"1","is_valid":true}"#)?,
+        ];
+        assert_eq!(
+            ready_audiences(&ids, audiences)?.map(|it| it.len()),
+            Some(2)
+        );
+
+        let audiences = vec![audience(r#"{"audience_id":"2","status":"SOMETHING_NEW"}"#)?];
+        let err = ready_audiences(&ids, audiences).err().unwrap();
+        assert!(
+            matches!(err, ApiError::Invalid(message) if message == "custom audience 2 SOMETHING_NEW")
+        );
+        Ok(())
+    }
+}
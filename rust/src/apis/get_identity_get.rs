@@ -0,0 +1,241 @@
+use crate::responses::ad::IdentityType;
+use crate::responses::{identity::Identity, page_info::PageInfo};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/identity/get/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    identity_type: Option<IdentityType>,
+    identity_authorized_bc_id: Option<String>,
+    filtering: Option<Filtering>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Filtering {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+}
+
+impl std::fmt::Display for Filtering {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", serde_json::to_string(&self).unwrap())
+    }
+}
+
+impl Api {
+    pub fn new(advertiser_id: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn identity_type(mut self, value: IdentityType) -> Self {
+        self.identity_type = Some(value);
+        self
+    }
+
+    pub fn identity_authorized_bc_id(mut self, value: &str) -> Self {
+        self.identity_authorized_bc_id = Some(value.to_owned());
+        self
+    }
+
+    pub fn filtering(mut self, value: Filtering) -> Self {
+        self.filtering = Some(value);
+        self
+    }
+
+    pub fn page(mut self, value: usize) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    pub fn page_size(mut self, value: usize) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        if let Some(identity_type) = self.identity_type {
+            query_parameters.push(("identity_type", identity_type.to_string()));
+        }
+        if let Some(identity_authorized_bc_id) = self.identity_authorized_bc_id {
+            query_parameters.push(("identity_authorized_bc_id", identity_authorized_bc_id));
+        }
+        if let Some(filtering) = self.filtering {
+            query_parameters.push(("filtering", filtering.to_string()));
+        }
+        if let Some(page) = self.page {
+            query_parameters.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query_parameters.push(("page_size", page_size.to_string()));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // TT_USERのidentity_idはTiktokOauthで取得したopen_idと同じ
+    // identity_type が未指定なら open_id を持つ TT_USER と BC_AUTH_TT だけを探す。BC_AUTH_TT は identity_authorized_bc_id がある場合のみ
+    pub async fn execute_find_by_open_id(
+        self,
+        bearer_code: &str,
+        open_id: &str,
+    ) -> Result<Option<Identity>, ApiError> {
+        for identity_type in self.search_identity_types() {
+            let api = self.clone().identity_type(identity_type);
+            if let Some(identity) = api.find_in_pages(bearer_code, open_id).await? {
+                return Ok(Some(identity));
+            }
+        }
+        Ok(None)
+    }
+
+    fn search_identity_types(&self) -> Vec<IdentityType> {
+        if let Some(identity_type) = self.identity_type.clone() {
+            return vec![identity_type];
+        }
+        let mut types = vec![IdentityType::TtUser];
+        if self.identity_authorized_bc_id.is_some() {
+            types.push(IdentityType::BcAuthTt);
+        }
+        types
+    }
+
+    async fn find_in_pages(
+        self,
+        bearer_code: &str,
+        open_id: &str,
+    ) -> Result<Option<Identity>, ApiError> {
+        let mut page = self.page.unwrap_or(1);
+        loop {
+            let res = self.clone().page(page).execute(bearer_code).await?;
+            if res.body.code != 0 {
+                return Err(ApiError::Api(
+                    res.body.code,
+                    res.body.message,
+                    res.status_code,
+                ));
+            }
+            let Some(data) = res.body.data else {
+                return Ok(None);
+            };
+            if let Some(identity) = data
+                .identity_list
+                .unwrap_or_default()
+                .into_iter()
+                .find(|it| it.identity_id.as_deref() == Some(open_id))
+            {
+                return Ok(Some(identity));
+            }
+            let total_page = data.page_info.and_then(|it| it.total_page).unwrap_or(0);
+            if page as i64 >= total_page {
+                return Ok(None);
+            }
+            page += 1;
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_list: Option<Vec<Identity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<PageInfo>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .identity_list
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .page_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_identity_get_search_identity_types -- --nocapture --test-threads=1
+    #[test]
+    fn test_identity_get_search_identity_types() {
+        let names = |api: Api| {
+            api.search_identity_types()
+                .iter()
+                .map(|it| it.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(Api::new("1", None)), vec!["TT_USER"]);
+        assert_eq!(
+            names(Api::new("1", None).identity_authorized_bc_id("2")),
+            vec!["TT_USER", "BC_AUTH_TT"]
+        );
+        assert_eq!(
+            names(Api::new("1", None).identity_type(IdentityType::AuthCode)),
+            vec!["AUTH_CODE"]
+        );
+    }
+}
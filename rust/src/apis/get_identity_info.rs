@@ -0,0 +1,111 @@
+use crate::responses::ad::IdentityType;
+use crate::responses::identity::Identity;
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/identity/info/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    identity_id: String,
+    identity_type: IdentityType,
+    identity_authorized_bc_id: Option<String>,
+}
+
+impl Api {
+    pub fn new(
+        advertiser_id: &str,
+        identity_id: &str,
+        identity_type: IdentityType,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            identity_id: identity_id.to_owned(),
+            identity_type,
+            ..Default::default()
+        }
+    }
+
+    pub fn identity_authorized_bc_id(mut self, value: &str) -> Self {
+        self.identity_authorized_bc_id = Some(value.to_owned());
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        query_parameters.push(("identity_id", self.identity_id));
+        query_parameters.push(("identity_type", self.identity_type.to_string()));
+        if let Some(identity_authorized_bc_id) = self.identity_authorized_bc_id {
+            query_parameters.push(("identity_authorized_bc_id", identity_authorized_bc_id));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_info: Option<Identity>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .identity_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,39 @@
+use crate::responses::oembed::OEmbed;
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_www_url},
+};
+use reqwest::RequestBuilder;
+
+// 公開エンドポイントなので認証は不要
+const URL: &str = "/oembed";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    url: String,
+}
+
+impl Api {
+    pub fn new(url: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            url: url.to_owned(),
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("url", self.url));
+        let client = reqwest::Client::new()
+            .get(make_www_url(URL, &self.options))
+            .query(&query_parameters);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self) -> Result<ApiResponse<OEmbed>, ApiError> {
+        execute_api(|| self.clone().build(), &self.options).await
+    }
+}
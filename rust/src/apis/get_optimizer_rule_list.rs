@@ -0,0 +1,208 @@
+use crate::responses::{optimizer_rule::OptimizerRule, page_info::PageInfo};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/optimizer/rule/list/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    filtering: Option<Filtering>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum RuleStatus {
+    #[serde(rename = "ON")]
+    #[default]
+    On,
+    #[serde(rename = "OFF")]
+    Off,
+}
+
+impl std::fmt::Display for RuleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::On => write!(f, "ON"),
+            Self::Off => write!(f, "OFF"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Filtering {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<RuleStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl std::fmt::Display for Filtering {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", serde_json::to_string(&self).unwrap())
+    }
+}
+
+impl Api {
+    pub fn new(advertiser_id: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn filtering(mut self, value: Filtering) -> Self {
+        self.filtering = Some(value);
+        self
+    }
+
+    pub fn page(mut self, value: usize) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    pub fn page_size(mut self, value: usize) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        if let Some(filtering) = self.filtering {
+            query_parameters.push(("filtering", filtering.to_string()));
+        }
+        if let Some(page) = self.page {
+            query_parameters.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query_parameters.push(("page_size", page_size.to_string()));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<OptimizerRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<PageInfo>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .rules
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .page_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::optimizer_rule::{ConditionSubject, Dimension, RuleStatus as Status};
+
+    // cargo test test_optimizer_rule_list_unknown_values -- --nocapture --test-threads=1
+    #[test]
+    fn test_optimizer_rule_list_unknown_values() -> anyhow::Result<()> {
+        let res: Response = serde_json::from_str(
+            r#"{
+                "request_id": "1",
+                "code": 0,
+                "message": "OK",
+                "data": {"rules": [{
+                    "rule_id": "1",
+                    "status": "ON",
+                    "apply_objects": [{"dimension": "SMART_PLUS", "pre_condition_type": "SELECTED"}],
+                    "conditions": [{"subject_type": "NEW_METRIC", "range_type": "LIFETIME", "match_type": "GT", "values": ["1"]}]
+                }, {
+                    "rule_id": "2",
+                    "status": "DELETED"
+                }]}
+            }"#,
+        )?;
+        assert!(res.is_empty_extra());
+        let rules = res.data.and_then(|it| it.rules).unwrap_or_default();
+        assert!(matches!(rules[0].status, Some(Status::On)));
+        let apply_objects = rules[0].apply_objects.clone().unwrap_or_default();
+        assert!(
+            matches!(&apply_objects[0].dimension, Some(Dimension::Unknown(value)) if value == "SMART_PLUS")
+        );
+        let conditions = rules[0].conditions.clone().unwrap_or_default();
+        assert!(matches!(
+            conditions[0].subject_type,
+            Some(ConditionSubject::Unknown(_))
+        ));
+        assert_eq!(
+            rules[1].status.as_ref().map(|it| it.to_string()).as_deref(),
+            Some("DELETED")
+        );
+
+        let filtering = Filtering {
+            status: Some(RuleStatus::Off),
+            ..Default::default()
+        };
+        assert_eq!(filtering.to_string(), r#"{"status":"OFF"}"#);
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    apis::{ApiResponse, execute_api, poll_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/page/lead/task/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    task_id: String,
+}
+
+impl Api {
+    pub fn new(advertiser_id: &str, task_id: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            task_id: task_id.to_owned(),
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        query_parameters.push(("task_id", self.task_id));
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    pub async fn execute_until_done(self, bearer_code: &str) -> Result<Data, ApiError> {
+        let api = &self;
+        poll_api(
+            || async move {
+                let res = api.clone().execute(bearer_code).await?;
+                if res.body.code != 0 {
+                    return Err(ApiError::Api(
+                        res.body.code,
+                        res.body.message,
+                        res.status_code,
+                    ));
+                }
+                let Some(data) = res.body.data else {
+                    return Ok(None);
+                };
+                match data.status {
+                    Some(Status::Succeed) => Ok(Some(data)),
+                    Some(Status::Failed) => Err(ApiError::Invalid(format!(
+                        "lead task {} FAILED",
+                        api.task_id
+                    ))),
+                    _ => Ok(None),
+                }
+            },
+            &self.options,
+        )
+        .await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Status {
+    #[serde(rename = "CREATED")]
+    #[default]
+    Created,
+    #[serde(rename = "RUNNING")]
+    Running,
+    #[serde(rename = "SUCCEED")]
+    Succeed,
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Created => write!(f, "CREATED"),
+            Self::Running => write!(f, "RUNNING"),
+            Self::Succeed => write!(f, "SUCCEED"),
+            Self::Failed => write!(f, "FAILED"),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::responses::lead::{Lead, LeadField};
+use crate::{
+    apis::execute_raw,
+    csv_stream::CsvStream,
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use std::collections::HashSet;
+
+const URL: &str = "/page/lead/task/download/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    task_id: String,
+}
+
+impl Api {
+    pub fn new(advertiser_id: &str, task_id: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            task_id: task_id.to_owned(),
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        query_parameters.push(("task_id", self.task_id));
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<LeadReader, ApiError> {
+        let response = execute_raw(|| self.clone().build(bearer_code), &self.options).await?;
+        LeadReader::new(response).await
+    }
+}
+
+pub struct LeadReader {
+    stream: CsvStream,
+}
+
+impl LeadReader {
+    async fn new(response: reqwest::Response) -> Result<Self, ApiError> {
+        Ok(Self {
+            stream: CsvStream::new(response).await?,
+        })
+    }
+
+    pub fn headers(&self) -> &[String] {
+        self.stream.headers()
+    }
+
+    pub async fn next_record(&mut self) -> Result<Option<Vec<String>>, ApiError> {
+        self.stream.next_record().await
+    }
+
+    // LeadFieldに無い列はカスタム質問としてcustom_answersに入れる
+    pub async fn next_lead(&mut self) -> Result<Option<Lead>, ApiError> {
+        let Some(record) = self.stream.next_record().await? else {
+            return Ok(None);
+        };
+        let fields: HashSet<String> = LeadField::all().iter().map(|it| it.to_string()).collect();
+        let mut lead = serde_json::Map::new();
+        let mut custom_answers = serde_json::Map::new();
+        for (header, value) in self.stream.headers().iter().zip(record) {
+            if value.is_empty() {
+                continue;
+            }
+            if fields.contains(header) {
+                lead.insert(header.clone(), serde_json::Value::String(value));
+            } else {
+                custom_answers.insert(header.clone(), serde_json::Value::String(value));
+            }
+        }
+        if !custom_answers.is_empty() {
+            lead.insert(
+                "custom_answers".to_owned(),
+                serde_json::Value::Object(custom_answers),
+            );
+        }
+        Ok(Some(serde_json::from_value(serde_json::Value::Object(
+            lead,
+        ))?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_page_lead_task_download_reader -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_page_lead_task_download_reader() -> anyhow::Result<()> {
+        let body = "lead_id,create_time,ad_id,email,What is your budget?\n\
+            7001,2026-01-01 10:00:00,123,foo@example.com,\"$1,000\"\n\
+            7002,2026-01-02 11:00:00,123,,\n";
+        let response = reqwest::Response::from(http::Response::new(body));
+        let mut reader = LeadReader::new(response).await?;
+
+        let lead = reader.next_lead().await?.unwrap();
+        assert_eq!(lead.lead_id.as_deref(), Some("7001"));
+        assert_eq!(lead.email.as_deref(), Some("foo@example.com"));
+        let custom_answers = lead.custom_answers.clone().unwrap_or_default();
+        assert_eq!(
+            custom_answers
+                .get("What is your budget?")
+                .map(|it| it.as_str()),
+            Some("$1,000")
+        );
+        assert!(lead.is_empty_extra());
+
+        let lead = reader.next_lead().await?.unwrap();
+        assert_eq!(lead.email, None);
+        assert!(lead.custom_answers.is_none());
+        assert!(lead.is_empty_extra());
+
+        assert!(reader.next_lead().await?.is_none());
+        Ok(())
+    }
+
+    // cargo test test_page_lead_task_download_error_json -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_page_lead_task_download_error_json() -> anyhow::Result<()> {
+        let body =
+            "\u{feff}{\"code\":40001,\"message\":\"task is not finished\",\"request_id\":\"123\"}";
+        let response = reqwest::Response::from(http::Response::new(body));
+        let err = LeadReader::new(response).await.err().unwrap();
+        assert!(
+            matches!(err, ApiError::Api(40001, message, _) if message == "task is not finished")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,337 @@
+use crate::responses::{page_info::PageInfo, report_row::ReportRow};
+use crate::responses::{
+    report_dimensions::ReportDimensionsField, report_metrics::ReportMetricsField,
+};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use itertools::Itertools;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const URL: &str = "/report/integrated/get/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    report_type: ReportType,
+    dimensions: HashSet<ReportDimensionsField>,
+    data_level: Option<DataLevel>,
+    metrics: Option<HashSet<ReportMetricsField>>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    query_lifetime: Option<bool>,
+    filtering: Option<Vec<Filter>>,
+    order_field: Option<String>,
+    order_type: Option<OrderType>,
+    page: Option<usize>,
+    page_size: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ReportType {
+    #[serde(rename = "BASIC")]
+    #[default]
+    Basic,
+    #[serde(rename = "AUDIENCE")]
+    Audience,
+    #[serde(rename = "PLAYABLE_MATERIAL")]
+    PlayableMaterial,
+    #[serde(rename = "CATALOG")]
+    Catalog,
+}
+
+impl std::fmt::Display for ReportType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Basic => write!(f, "BASIC"),
+            Self::Audience => write!(f, "AUDIENCE"),
+            Self::PlayableMaterial => write!(f, "PLAYABLE_MATERIAL"),
+            Self::Catalog => write!(f, "CATALOG"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum DataLevel {
+    #[serde(rename = "AUCTION_AD")]
+    #[default]
+    AuctionAd,
+    #[serde(rename = "AUCTION_ADGROUP")]
+    AuctionAdgroup,
+    #[serde(rename = "AUCTION_CAMPAIGN")]
+    AuctionCampaign,
+    #[serde(rename = "AUCTION_ADVERTISER")]
+    AuctionAdvertiser,
+}
+
+impl std::fmt::Display for DataLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AuctionAd => write!(f, "AUCTION_AD"),
+            Self::AuctionAdgroup => write!(f, "AUCTION_ADGROUP"),
+            Self::AuctionCampaign => write!(f, "AUCTION_CAMPAIGN"),
+            Self::AuctionAdvertiser => write!(f, "AUCTION_ADVERTISER"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum OrderType {
+    #[serde(rename = "DESC")]
+    #[default]
+    Desc,
+    #[serde(rename = "ASC")]
+    Asc,
+}
+
+impl std::fmt::Display for OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Desc => write!(f, "DESC"),
+            Self::Asc => write!(f, "ASC"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum FilterType {
+    #[serde(rename = "IN")]
+    #[default]
+    In,
+    #[serde(rename = "MATCH")]
+    Match,
+    #[serde(rename = "GREATER_EQUAL")]
+    GreaterEqual,
+    #[serde(rename = "GREATER_THAN")]
+    GreaterThan,
+    #[serde(rename = "LOWER_EQUAL")]
+    LowerEqual,
+    #[serde(rename = "LOWER_THAN")]
+    LowerThan,
+    #[serde(rename = "BETWEEN")]
+    Between,
+}
+
+impl std::fmt::Display for FilterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::In => write!(f, "IN"),
+            Self::Match => write!(f, "MATCH"),
+            Self::GreaterEqual => write!(f, "GREATER_EQUAL"),
+            Self::GreaterThan => write!(f, "GREATER_THAN"),
+            Self::LowerEqual => write!(f, "LOWER_EQUAL"),
+            Self::LowerThan => write!(f, "LOWER_THAN"),
+            Self::Between => write!(f, "BETWEEN"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Filter {
+    pub field_name: String,
+    pub filter_type: FilterType,
+    pub filter_value: String,
+}
+
+impl Api {
+    pub fn new(
+        advertiser_id: &str,
+        report_type: ReportType,
+        dimensions: HashSet<ReportDimensionsField>,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            report_type,
+            dimensions,
+            ..Default::default()
+        }
+    }
+
+    pub fn data_level(mut self, value: DataLevel) -> Self {
+        self.data_level = Some(value);
+        self
+    }
+
+    pub fn metrics(mut self, value: HashSet<ReportMetricsField>) -> Self {
+        self.metrics = Some(value);
+        self
+    }
+
+    pub fn start_date(mut self, value: &str) -> Self {
+        self.start_date = Some(value.to_owned());
+        self
+    }
+
+    pub fn end_date(mut self, value: &str) -> Self {
+        self.end_date = Some(value.to_owned());
+        self
+    }
+
+    pub fn query_lifetime(mut self, value: bool) -> Self {
+        self.query_lifetime = Some(value);
+        self
+    }
+
+    pub fn filtering(mut self, value: Vec<Filter>) -> Self {
+        self.filtering = Some(value);
+        self
+    }
+
+    pub fn order_field(mut self, value: &str) -> Self {
+        self.order_field = Some(value.to_owned());
+        self
+    }
+
+    pub fn order_type(mut self, value: OrderType) -> Self {
+        self.order_type = Some(value);
+        self
+    }
+
+    pub fn page(mut self, value: usize) -> Self {
+        self.page = Some(value);
+        self
+    }
+
+    pub fn page_size(mut self, value: usize) -> Self {
+        self.page_size = Some(value);
+        self
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        query_parameters.push(("report_type", self.report_type.to_string()));
+        query_parameters.push((
+            "dimensions",
+            format!("[\"{}\"]", self.dimensions.iter().join("\",\"")),
+        ));
+        if let Some(data_level) = self.data_level {
+            query_parameters.push(("data_level", data_level.to_string()));
+        }
+        if let Some(metrics) = self.metrics {
+            query_parameters.push(("metrics", format!("[\"{}\"]", metrics.iter().join("\",\""))));
+        }
+        if let Some(start_date) = self.start_date {
+            query_parameters.push(("start_date", start_date));
+        }
+        if let Some(end_date) = self.end_date {
+            query_parameters.push(("end_date", end_date));
+        }
+        if let Some(query_lifetime) = self.query_lifetime {
+            query_parameters.push(("query_lifetime", query_lifetime.to_string()));
+        }
+        if let Some(filtering) = self.filtering {
+            query_parameters.push(("filtering", serde_json::to_string(&filtering).unwrap()));
+        }
+        if let Some(order_field) = self.order_field {
+            query_parameters.push(("order_field", order_field));
+        }
+        if let Some(order_type) = self.order_type {
+            query_parameters.push(("order_type", order_type.to_string()));
+        }
+        if let Some(page) = self.page {
+            query_parameters.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query_parameters.push(("page_size", page_size.to_string()));
+        }
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    pub async fn execute_all(self, bearer_code: &str) -> Result<Vec<ReportRow>, ApiError> {
+        let mut rows = vec![];
+        let mut page = self.page.unwrap_or(1);
+        loop {
+            let res = self.clone().page(page).execute(bearer_code).await?;
+            if res.body.code != 0 {
+                return Err(ApiError::Api(
+                    res.body.code,
+                    res.body.message,
+                    res.status_code,
+                ));
+            }
+            let Some(data) = res.body.data else {
+                break;
+            };
+            rows.extend(data.list.unwrap_or_default());
+            let total_page = data.page_info.and_then(|it| it.total_page).unwrap_or(0);
+            if page as i64 >= total_page {
+                break;
+            }
+            page += 1;
+        }
+        Ok(rows)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list: Option<Vec<ReportRow>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<PageInfo>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .list
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .page_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
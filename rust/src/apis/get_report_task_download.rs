@@ -0,0 +1,159 @@
+use crate::responses::report_dimensions::{ReportDimensions, ReportDimensionsField};
+use crate::responses::report_metrics::ReportMetrics;
+use crate::responses::report_row::ReportRow;
+use crate::{
+    apis::execute_raw,
+    csv_stream::CsvStream,
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+
+const URL: &str = "/report/task/download/";
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    task_id: String,
+}
+
+impl Api {
+    pub fn new(advertiser_id: &str, task_id: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            task_id: task_id.to_owned(),
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("advertiser_id", self.advertiser_id));
+        query_parameters.push(("task_id", self.task_id));
+        let client = reqwest::Client::new()
+            .get(make_url(URL, &self.options))
+            .query(&query_parameters)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ReportReader, ApiError> {
+        let response = execute_raw(|| self.clone().build(bearer_code), &self.options).await?;
+        ReportReader::new(response).await
+    }
+}
+
+pub struct ReportReader {
+    stream: CsvStream,
+}
+
+impl ReportReader {
+    async fn new(response: reqwest::Response) -> Result<Self, ApiError> {
+        Ok(Self {
+            stream: CsvStream::new(response).await?,
+        })
+    }
+
+    pub fn headers(&self) -> &[String] {
+        self.stream.headers()
+    }
+
+    pub async fn next_record(&mut self) -> Result<Option<Vec<String>>, ApiError> {
+        self.stream.next_record().await
+    }
+
+    pub async fn next_row(&mut self) -> Result<Option<ReportRow>, ApiError> {
+        let Some(record) = self.stream.next_record().await? else {
+            return Ok(None);
+        };
+        make_row(self.stream.headers(), record).map(Some)
+    }
+}
+
+fn make_row(headers: &[String], record: Vec<String>) -> Result<ReportRow, ApiError> {
+    let dimension_names: Vec<String> = ReportDimensionsField::all()
+        .iter()
+        .map(|it| it.to_string())
+        .collect();
+    let mut dimensions = serde_json::Map::new();
+    let mut metrics = serde_json::Map::new();
+    for (header, value) in headers.iter().zip(record) {
+        if value.is_empty() {
+            continue;
+        }
+        if dimension_names.contains(header) {
+            dimensions.insert(header.clone(), serde_json::Value::String(value));
+        } else {
+            metrics.insert(header.clone(), serde_json::Value::String(value));
+        }
+    }
+    Ok(ReportRow {
+        dimensions: Some(serde_json::from_value::<ReportDimensions>(
+            serde_json::Value::Object(dimensions),
+        )?),
+        metrics: Some(serde_json::from_value::<ReportMetrics>(
+            serde_json::Value::Object(metrics),
+        )?),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_report_task_download_reader -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_report_task_download_reader() -> anyhow::Result<()> {
+        let body = "\u{feff}stat_time_day,campaign_id,country_code,spend,impressions\n\
+            2026-01-01,123,US,1.50,100\n\
+            \"2026-01-02\",\"4,56\",JP,-,\n";
+        let response = reqwest::Response::from(http::Response::new(body));
+        let mut reader = ReportReader::new(response).await?;
+        assert_eq!(
+            reader.headers(),
+            &[
+                "stat_time_day",
+                "campaign_id",
+                "country_code",
+                "spend",
+                "impressions"
+            ]
+        );
+
+        let row = reader.next_row().await?.unwrap();
+        let dimensions = row.dimensions.unwrap();
+        let metrics = row.metrics.unwrap();
+        assert_eq!(dimensions.campaign_id.as_deref(), Some("123"));
+        assert_eq!(dimensions.country_code, Some(isocountry::CountryCode::USA));
+        assert_eq!(metrics.spend, Some(1.5));
+        assert_eq!(metrics.impressions, Some(100));
+
+        let row = reader.next_row().await?.unwrap();
+        assert_eq!(row.dimensions.unwrap().campaign_id.as_deref(), Some("4,56"));
+        assert_eq!(row.metrics.unwrap().spend, None);
+
+        assert!(reader.next_row().await?.is_none());
+        Ok(())
+    }
+
+    // cargo test test_report_task_download_error_json -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_report_task_download_error_json() -> anyhow::Result<()> {
+        let body = r#"{"code":40002,"message":"task not found","request_id":"123","data":{}}"#;
+        let response = reqwest::Response::from(http::Response::new(body));
+        let err = ReportReader::new(response).await.err().unwrap();
+        assert!(matches!(err, ApiError::Api(40002, message, _) if message == "task not found"));
+
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .header("Content-Type", "application/json")
+                .body("not json")?,
+        );
+        let err = ReportReader::new(response).await.err().unwrap();
+        assert!(matches!(err, ApiError::Other(text, _) if text == "not json"));
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use crate::responses::ad::Ad;
+use crate::responses::ad::AdFormat;
+use crate::responses::ad::CallToAction;
+use crate::responses::ad::IdentityType;
+use crate::responses::campaign::OperationStatus;
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/ad/create/";
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Creative {
+    pub ad_name: String,
+    pub ad_format: AdFormat,
+    pub identity_type: IdentityType,
+    pub identity_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_authorized_bc_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action: Option<CallToAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landing_page_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiktok_item_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_pixel_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub advertiser_id: String,
+    pub adgroup_id: String,
+    pub creatives: Vec<Creative>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_status: Option<OperationStatus>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(body: Body, options: Option<TiktokOptions>) -> Self {
+        Self { options, body }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .json(&self.body)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creatives: Option<Vec<Ad>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .creatives
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,129 @@
+use crate::responses::ad_group::AdGroup;
+use crate::responses::ad_group::AgeGroup;
+use crate::responses::ad_group::BidType;
+use crate::responses::ad_group::BillingEvent;
+use crate::responses::ad_group::Gender;
+use crate::responses::ad_group::OperatingSystem;
+use crate::responses::ad_group::OptimizationGoal;
+use crate::responses::ad_group::Pacing;
+use crate::responses::ad_group::Placement;
+use crate::responses::ad_group::PlacementType;
+use crate::responses::ad_group::ScheduleType;
+use crate::responses::campaign::BudgetMode;
+use crate::responses::campaign::OperationStatus;
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/adgroup/create/";
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub advertiser_id: String,
+    pub campaign_id: String,
+    pub adgroup_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement_type: Option<PlacementType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placements: Option<Vec<Placement>>,
+    pub location_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_groups: Option<Vec<AgeGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub languages: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interest_category_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interest_keyword_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operating_systems: Option<Vec<OperatingSystem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_mode: Option<BudgetMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_type: Option<ScheduleType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dayparting: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimization_goal: Option<OptimizationGoal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimization_event: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pixel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_type: Option<BidType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversion_bid_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_event: Option<BillingEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pacing: Option<Pacing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_status: Option<OperationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(body: Body, options: Option<TiktokOptions>) -> Self {
+        Self { options, body }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .json(&self.body)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<AdGroup>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,291 @@
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/catalog/product/upload/";
+const MAX_PRODUCTS: usize = 5000;
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Condition {
+    #[serde(rename = "NEW")]
+    #[default]
+    New,
+    #[serde(rename = "REFURBISHED")]
+    Refurbished,
+    #[serde(rename = "USED")]
+    Used,
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::New => write!(f, "NEW"),
+            Self::Refurbished => write!(f, "REFURBISHED"),
+            Self::Used => write!(f, "USED"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Availability {
+    #[serde(rename = "IN_STOCK")]
+    #[default]
+    InStock,
+    #[serde(rename = "OUT_OF_STOCK")]
+    OutOfStock,
+    #[serde(rename = "PREORDER")]
+    Preorder,
+    #[serde(rename = "AVAILABLE_FOR_ORDER")]
+    AvailableForOrder,
+    #[serde(rename = "DISCONTINUED")]
+    Discontinued,
+}
+
+impl std::fmt::Display for Availability {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::InStock => write!(f, "IN_STOCK"),
+            Self::OutOfStock => write!(f, "OUT_OF_STOCK"),
+            Self::Preorder => write!(f, "PREORDER"),
+            Self::AvailableForOrder => write!(f, "AVAILABLE_FOR_ORDER"),
+            Self::Discontinued => write!(f, "DISCONTINUED"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Price {
+    pub price: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sale_price: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LandingPage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landing_page_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Product {
+    pub sku_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_group_id: Option<String>,
+    pub title: String,
+    pub description: String,
+    pub availability: Availability,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<Condition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+    pub image_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_image_urls: Option<Vec<String>>,
+    pub price: Price,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landing_page: Option<LandingPage>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub bc_id: String,
+    pub catalog_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_id: Option<String>,
+    pub products: Vec<Product>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(body: Body, options: Option<TiktokOptions>) -> Self {
+        Self { options, body }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .json(&self.body)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // 件数とサイズの上限で分割して送信する。失敗したバッチがあっても残りは送る
+    // 1件で上限を超える商品がある場合は何も送らずにエラーにする
+    pub async fn execute_batches(self, bearer_code: &str) -> Result<Vec<BatchResult>, ApiError> {
+        let mut results = vec![];
+        for products in make_batches(&self.body, MAX_PRODUCTS, MAX_BODY_BYTES)? {
+            let sku_ids = products.iter().map(|it| it.sku_id.clone()).collect();
+            let api = Self {
+                options: self.options.clone(),
+                body: Body {
+                    products,
+                    ..self.body.clone()
+                },
+            };
+            let result = match api.execute(bearer_code).await {
+                Ok(res) if res.body.code != 0 => Err(ApiError::Api(
+                    res.body.code,
+                    res.body.message,
+                    res.status_code,
+                )),
+                Ok(res) => Ok(res.body.data.unwrap_or_default()),
+                Err(err) => Err(err),
+            };
+            results.push(BatchResult { sku_ids, result });
+        }
+        Ok(results)
+    }
+}
+
+#[derive(Debug)]
+pub struct BatchResult {
+    pub sku_ids: Vec<String>,
+    pub result: Result<Data, ApiError>,
+}
+
+// bc_id などの商品以外の部分もサイズに含める。商品の区切りのカンマ分も数える
+fn make_batches(
+    body: &Body,
+    max_count: usize,
+    max_bytes: usize,
+) -> Result<Vec<Vec<Product>>, ApiError> {
+    let envelope = serde_json::to_vec(&Body {
+        products: vec![],
+        ..body.clone()
+    })?
+    .len();
+    let mut batches = vec![];
+    let mut batch = vec![];
+    let mut bytes = envelope;
+    for product in &body.products {
+        let size = serde_json::to_vec(product)?.len() + 1;
+        if envelope + size > max_bytes {
+            return Err(ApiError::Invalid(format!(
+                "product {} is too large: {} bytes",
+                product.sku_id, size
+            )));
+        }
+        if !batch.is_empty() && (batch.len() >= max_count || bytes + size > max_bytes) {
+            batches.push(std::mem::take(&mut batch));
+            bytes = envelope;
+        }
+        bytes += size;
+        batch.push(product.clone());
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    Ok(batches)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_log_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_catalog_product_upload_make_batches -- --nocapture --test-threads=1
+    #[test]
+    fn test_catalog_product_upload_make_batches() -> anyhow::Result<()> {
+        let body = Body {
+            bc_id: "1".to_owned(),
+            catalog_id: "2".to_owned(),
+            feed_id: Some("3".to_owned()),
+            products: (0..7)
+                .map(|i| Product {
+                    sku_id: format!("sku{}", i),
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        let batches = make_batches(&body, 3, MAX_BODY_BYTES)?;
+        assert_eq!(
+            batches.iter().map(|it| it.len()).collect::<Vec<_>>(),
+            vec![3, 3, 1]
+        );
+
+        // 分割したバッチのリクエストボディが上限を超えない
+        let size = serde_json::to_vec(&body.products[0])?.len() + 1;
+        let envelope = serde_json::to_vec(&Body {
+            products: vec![],
+            ..body.clone()
+        })?
+        .len();
+        let max_bytes = envelope + size * 2;
+        let batches = make_batches(&body, MAX_PRODUCTS, max_bytes)?;
+        assert_eq!(batches.len(), 4);
+        assert_eq!(batches[3][0].sku_id, "sku6");
+        for products in batches {
+            let len = serde_json::to_vec(&Body {
+                products,
+                ..body.clone()
+            })?
+            .len();
+            assert!(len <= max_bytes);
+        }
+
+        // 1件で上限を超える商品はエラー
+        let err = make_batches(&body, MAX_PRODUCTS, envelope + size - 1)
+            .err()
+            .unwrap();
+        assert!(matches!(err, ApiError::Invalid(_)));
+        Ok(())
+    }
+}
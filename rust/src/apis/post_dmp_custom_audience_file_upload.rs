@@ -0,0 +1,225 @@
+use crate::upload::{file_md5, file_part};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::{RequestBuilder, multipart::Form};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const URL: &str = "/dmp/custom_audience/file/upload/";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum CalculateType {
+    #[serde(rename = "EMAIL_SHA256")]
+    #[default]
+    EmailSha256,
+    #[serde(rename = "PHONE_SHA256")]
+    PhoneSha256,
+    #[serde(rename = "IDFA_SHA256")]
+    IdfaSha256,
+    #[serde(rename = "GAID_SHA256")]
+    GaidSha256,
+    #[serde(rename = "IDFA_MD5")]
+    IdfaMd5,
+    #[serde(rename = "GAID_MD5")]
+    GaidMd5,
+}
+
+impl std::fmt::Display for CalculateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::EmailSha256 => write!(f, "EMAIL_SHA256"),
+            Self::PhoneSha256 => write!(f, "PHONE_SHA256"),
+            Self::IdfaSha256 => write!(f, "IDFA_SHA256"),
+            Self::GaidSha256 => write!(f, "GAID_SHA256"),
+            Self::IdfaMd5 => write!(f, "IDFA_MD5"),
+            Self::GaidMd5 => write!(f, "GAID_MD5"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    file: PathBuf,
+    file_size: Option<u64>,
+    file_signature: Option<String>,
+    calculate_type: CalculateType,
+}
+
+impl Api {
+    pub fn new(
+        advertiser_id: &str,
+        file: &Path,
+        calculate_type: CalculateType,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            file: file.to_owned(),
+            calculate_type,
+            ..Default::default()
+        }
+    }
+
+    pub fn file_signature(mut self, value: &str) -> Self {
+        self.file_signature = Some(value.to_owned());
+        self
+    }
+
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut form = Form::new()
+            .text("advertiser_id", self.advertiser_id)
+            .text("calculate_type", self.calculate_type.to_string())
+            .part("file", file_part(&self.file, self.file_size));
+        if let Some(file_signature) = self.file_signature {
+            form = form.text("file_signature", file_signature);
+        }
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .multipart(form)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(mut self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        let (signature, size) = file_md5(&self.file).await?;
+        self.file_signature.get_or_insert(signature);
+        self.file_size = Some(size);
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+// 1行に1件、正規化してハッシュ化した値を書き出す
+#[cfg(feature = "audience")]
+pub async fn write_audience_file<I, S>(
+    path: &Path,
+    calculate_type: &CalculateType,
+    values: I,
+) -> Result<u64, ApiError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    use crate::hashing::{
+        hash_email, hash_gaid, hash_gaid_md5, hash_idfa, hash_idfa_md5, hash_phone,
+    };
+    use tokio::io::AsyncWriteExt;
+
+    let hash: fn(&str) -> Result<String, ApiError> = match calculate_type {
+        CalculateType::EmailSha256 => |it| Ok(hash_email(it)),
+        CalculateType::PhoneSha256 => hash_phone,
+        CalculateType::IdfaSha256 => |it| Ok(hash_idfa(it)),
+        CalculateType::GaidSha256 => |it| Ok(hash_gaid(it)),
+        CalculateType::IdfaMd5 => |it| Ok(hash_idfa_md5(it)),
+        CalculateType::GaidMd5 => |it| Ok(hash_gaid_md5(it)),
+    };
+    let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create(path).await?);
+    let mut count = 0;
+    for value in values {
+        let value = value.as_ref().trim();
+        if value.is_empty() {
+            continue;
+        }
+        writer.write_all(hash(value)?.as_bytes()).await?;
+        writer.write_all(b"\n").await?;
+        count += 1;
+    }
+    writer.flush().await?;
+    Ok(count)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(all(test, feature = "audience"))]
+mod tests {
+    use super::*;
+    use crate::hashing::hash_email;
+
+    // cargo test test_custom_audience_write_audience_file --all-features -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_custom_audience_write_audience_file() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join("tiktok_business_test_write_audience_file.txt");
+        let count = write_audience_file(
+            &path,
+            &CalculateType::EmailSha256,
+            ["Foo@Example.com", "", " bar@example.com "],
+        )
+        .await?;
+        let text = std::fs::read_to_string(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(count, 2);
+        assert_eq!(
+            text,
+            format!(
+                "{}\n{}\n",
+                hash_email("foo@example.com"),
+                hash_email("bar@example.com")
+            )
+        );
+
+        let count = write_audience_file(
+            &path,
+            &CalculateType::IdfaMd5,
+            ["6d92078a-8246-4ba4-ae5b-76104861e7dc"],
+        )
+        .await?;
+        let text = std::fs::read_to_string(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(count, 1);
+        assert_eq!(text, "f2d1311ca5c1ecb214c19a26e9ddbad0\n");
+
+        // 電話番号は国番号付きのE.164形式でなければエラー
+        let result =
+            write_audience_file(&path, &CalculateType::PhoneSha256, ["090-1234-5678"]).await;
+        std::fs::remove_file(&path)?;
+        assert!(matches!(result, Err(ApiError::Invalid(_))));
+        Ok(())
+    }
+}
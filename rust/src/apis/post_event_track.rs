@@ -0,0 +1,351 @@
+use crate::hashing::{hash_email, hash_external_id, hash_phone};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/event/track/";
+const MAX_EVENTS: usize = 1000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum EventSource {
+    #[serde(rename = "web")]
+    #[default]
+    Web,
+    #[serde(rename = "app")]
+    App,
+    #[serde(rename = "offline")]
+    Offline,
+    #[serde(rename = "crm")]
+    Crm,
+}
+
+impl std::fmt::Display for EventSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Web => write!(f, "web"),
+            Self::App => write!(f, "app"),
+            Self::Offline => write!(f, "offline"),
+            Self::Crm => write!(f, "crm"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum EventName {
+    #[serde(rename = "AddPaymentInfo")]
+    #[default]
+    AddPaymentInfo,
+    #[serde(rename = "AddToCart")]
+    AddToCart,
+    #[serde(rename = "AddToWishlist")]
+    AddToWishlist,
+    #[serde(rename = "ApplicationApproval")]
+    ApplicationApproval,
+    #[serde(rename = "ClickButton")]
+    ClickButton,
+    #[serde(rename = "CompletePayment")]
+    CompletePayment,
+    #[serde(rename = "CompleteRegistration")]
+    CompleteRegistration,
+    #[serde(rename = "Contact")]
+    Contact,
+    #[serde(rename = "CustomizeProduct")]
+    CustomizeProduct,
+    #[serde(rename = "Download")]
+    Download,
+    #[serde(rename = "FindLocation")]
+    FindLocation,
+    #[serde(rename = "InitiateCheckout")]
+    InitiateCheckout,
+    #[serde(rename = "Lead")]
+    Lead,
+    #[serde(rename = "PlaceAnOrder")]
+    PlaceAnOrder,
+    #[serde(rename = "Purchase")]
+    Purchase,
+    #[serde(rename = "Schedule")]
+    Schedule,
+    #[serde(rename = "Search")]
+    Search,
+    #[serde(rename = "StartTrial")]
+    StartTrial,
+    #[serde(rename = "SubmitApplication")]
+    SubmitApplication,
+    #[serde(rename = "SubmitForm")]
+    SubmitForm,
+    #[serde(rename = "Subscribe")]
+    Subscribe,
+    #[serde(rename = "ViewContent")]
+    ViewContent,
+}
+
+impl std::fmt::Display for EventName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AddPaymentInfo => write!(f, "AddPaymentInfo"),
+            Self::AddToCart => write!(f, "AddToCart"),
+            Self::AddToWishlist => write!(f, "AddToWishlist"),
+            Self::ApplicationApproval => write!(f, "ApplicationApproval"),
+            Self::ClickButton => write!(f, "ClickButton"),
+            Self::CompletePayment => write!(f, "CompletePayment"),
+            Self::CompleteRegistration => write!(f, "CompleteRegistration"),
+            Self::Contact => write!(f, "Contact"),
+            Self::CustomizeProduct => write!(f, "CustomizeProduct"),
+            Self::Download => write!(f, "Download"),
+            Self::FindLocation => write!(f, "FindLocation"),
+            Self::InitiateCheckout => write!(f, "InitiateCheckout"),
+            Self::Lead => write!(f, "Lead"),
+            Self::PlaceAnOrder => write!(f, "PlaceAnOrder"),
+            Self::Purchase => write!(f, "Purchase"),
+            Self::Schedule => write!(f, "Schedule"),
+            Self::Search => write!(f, "Search"),
+            Self::StartTrial => write!(f, "StartTrial"),
+            Self::SubmitApplication => write!(f, "SubmitApplication"),
+            Self::SubmitForm => write!(f, "SubmitForm"),
+            Self::Subscribe => write!(f, "Subscribe"),
+            Self::ViewContent => write!(f, "ViewContent"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttclid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttp: Option<String>,
+}
+
+// email, phone, external_idは正規化してSHA-256でハッシュ化する。生の値は直接設定できない
+// phone は国番号付きのE.164形式で渡す
+impl User {
+    pub fn email(mut self, value: &str) -> Self {
+        self.email = Some(hash_email(value));
+        self
+    }
+
+    pub fn phone(mut self, value: &str) -> Result<Self, ApiError> {
+        self.phone = Some(hash_phone(value)?);
+        Ok(self)
+    }
+
+    pub fn external_id(mut self, value: &str) -> Self {
+        self.external_id = Some(hash_external_id(value));
+        self
+    }
+
+    pub fn ip(mut self, value: &str) -> Self {
+        self.ip = Some(value.to_owned());
+        self
+    }
+
+    pub fn user_agent(mut self, value: &str) -> Self {
+        self.user_agent = Some(value.to_owned());
+        self
+    }
+
+    pub fn ttclid(mut self, value: &str) -> Self {
+        self.ttclid = Some(value.to_owned());
+        self
+    }
+
+    pub fn ttp(mut self, value: &str) -> Self {
+        self.ttp = Some(value.to_owned());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Content {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Properties {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contents: Option<Vec<Content>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Page {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referrer: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Event {
+    pub event: EventName,
+    pub event_time: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Properties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<Page>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub event_source: EventSource,
+    pub event_source_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_event_code: Option<String>,
+    pub data: Vec<Event>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(body: Body, options: Option<TiktokOptions>) -> Self {
+        Self { options, body }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .json(&self.body)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        if self.body.data.len() > MAX_EVENTS {
+            return Err(ApiError::Invalid(format!(
+                "events over {} {}",
+                MAX_EVENTS,
+                self.body.data.len()
+            )));
+        }
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // 上限ごとに分割して送信する。失敗したバッチがあっても残りは送信する
+    pub async fn execute_batches(self, bearer_code: &str) -> Vec<BatchResult> {
+        let mut results = vec![];
+        for events in self.body.data.chunks(MAX_EVENTS) {
+            let api = Self {
+                options: self.options.clone(),
+                body: Body {
+                    data: events.to_vec(),
+                    ..self.body.clone()
+                },
+            };
+            let result = match api.execute(bearer_code).await {
+                Ok(res) if res.body.code != 0 => Err(ApiError::Api(
+                    res.body.code,
+                    res.body.message,
+                    res.status_code,
+                )),
+                Ok(res) => Ok(res.body),
+                Err(err) => Err(err),
+            };
+            results.push(BatchResult {
+                events: events.to_vec(),
+                result,
+            });
+        }
+        results
+    }
+}
+
+// 送信したイベントと結果。失敗したバッチの events だけを再送できる
+#[derive(Debug)]
+pub struct BatchResult {
+    pub events: Vec<Event>,
+    pub result: Result<Response, ApiError>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashing::sha256_hex;
+
+    // cargo test test_event_track_user_hash --all-features -- --nocapture --test-threads=1
+    #[test]
+    fn test_event_track_user_hash() -> anyhow::Result<()> {
+        let user = User::default()
+            .email("  Foo@Example.COM ")
+            .phone("+1 (213) 373-4253")?;
+        assert_eq!(
+            user.email.as_deref(),
+            Some(hash_email("foo@example.com").as_str())
+        );
+        assert_eq!(
+            user.email.as_deref(),
+            Some("321ba197033e81286fedb719d60d4ed5cecaed170733cb4a92013811afc0e3b6")
+        );
+        assert_eq!(user.phone, Some(sha256_hex("+12133734253")));
+        assert_eq!(user.phone.as_ref().map(|it| it.len()), Some(64));
+
+        assert!(matches!(
+            User::default().phone("090-1234-5678"),
+            Err(ApiError::Invalid(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use crate::responses::ad_image::AdImage;
+use crate::upload::{file_md5, file_part};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::{RequestBuilder, multipart::Form};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const URL: &str = "/file/image/ad/upload/";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum UploadType {
+    #[serde(rename = "UPLOAD_BY_FILE")]
+    #[default]
+    UploadByFile,
+    #[serde(rename = "UPLOAD_BY_URL")]
+    UploadByUrl,
+    #[serde(rename = "UPLOAD_BY_FILE_ID")]
+    UploadByFileId,
+}
+
+impl std::fmt::Display for UploadType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UploadByFile => write!(f, "UPLOAD_BY_FILE"),
+            Self::UploadByUrl => write!(f, "UPLOAD_BY_URL"),
+            Self::UploadByFileId => write!(f, "UPLOAD_BY_FILE_ID"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    upload_type: UploadType,
+    image_file: Option<PathBuf>,
+    image_file_size: Option<u64>,
+    image_signature: Option<String>,
+    image_url: Option<String>,
+    file_id: Option<String>,
+    file_name: Option<String>,
+}
+
+impl Api {
+    pub fn new_by_file(
+        advertiser_id: &str,
+        image_file: &Path,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            upload_type: UploadType::UploadByFile,
+            image_file: Some(image_file.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn new_by_url(
+        advertiser_id: &str,
+        image_url: &str,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            upload_type: UploadType::UploadByUrl,
+            image_url: Some(image_url.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn new_by_file_id(
+        advertiser_id: &str,
+        file_id: &str,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            upload_type: UploadType::UploadByFileId,
+            file_id: Some(file_id.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn image_signature(mut self, value: &str) -> Self {
+        self.image_signature = Some(value.to_owned());
+        self
+    }
+
+    pub fn file_name(mut self, value: &str) -> Self {
+        self.file_name = Some(value.to_owned());
+        self
+    }
+
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut form = Form::new()
+            .text("advertiser_id", self.advertiser_id)
+            .text("upload_type", self.upload_type.to_string());
+        if let Some(image_file) = self.image_file {
+            form = form.part("image_file", file_part(&image_file, self.image_file_size));
+        }
+        if let Some(image_signature) = self.image_signature {
+            form = form.text("image_signature", image_signature);
+        }
+        if let Some(image_url) = self.image_url {
+            form = form.text("image_url", image_url);
+        }
+        if let Some(file_id) = self.file_id {
+            form = form.text("file_id", file_id);
+        }
+        if let Some(file_name) = self.file_name {
+            form = form.text("file_name", file_name);
+        }
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .multipart(form)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(mut self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        if let Some(image_file) = self.image_file.as_ref() {
+            let (signature, size) = file_md5(image_file).await?;
+            self.image_signature.get_or_insert(signature);
+            self.image_file_size = Some(size);
+        }
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<AdImage>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,218 @@
+use crate::responses::ad_video::AdVideo;
+use crate::upload::{file_md5, file_part};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::{RequestBuilder, multipart::Form};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const URL: &str = "/file/video/ad/upload/";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum UploadType {
+    #[serde(rename = "UPLOAD_BY_FILE")]
+    #[default]
+    UploadByFile,
+    #[serde(rename = "UPLOAD_BY_URL")]
+    UploadByUrl,
+    #[serde(rename = "UPLOAD_BY_FILE_ID")]
+    UploadByFileId,
+    #[serde(rename = "UPLOAD_BY_VIDEO_ID")]
+    UploadByVideoId,
+}
+
+impl std::fmt::Display for UploadType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UploadByFile => write!(f, "UPLOAD_BY_FILE"),
+            Self::UploadByUrl => write!(f, "UPLOAD_BY_URL"),
+            Self::UploadByFileId => write!(f, "UPLOAD_BY_FILE_ID"),
+            Self::UploadByVideoId => write!(f, "UPLOAD_BY_VIDEO_ID"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    upload_type: UploadType,
+    video_file: Option<PathBuf>,
+    video_file_size: Option<u64>,
+    video_signature: Option<String>,
+    video_url: Option<String>,
+    file_id: Option<String>,
+    video_id: Option<String>,
+    file_name: Option<String>,
+    is_third_party: Option<bool>,
+    flaw_detect: Option<bool>,
+    auto_fix_enabled: Option<bool>,
+    auto_bind_enabled: Option<bool>,
+}
+
+impl Api {
+    pub fn new_by_file(
+        advertiser_id: &str,
+        video_file: &Path,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            upload_type: UploadType::UploadByFile,
+            video_file: Some(video_file.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn new_by_url(
+        advertiser_id: &str,
+        video_url: &str,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            upload_type: UploadType::UploadByUrl,
+            video_url: Some(video_url.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn new_by_file_id(
+        advertiser_id: &str,
+        file_id: &str,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            upload_type: UploadType::UploadByFileId,
+            file_id: Some(file_id.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn new_by_video_id(
+        advertiser_id: &str,
+        video_id: &str,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            upload_type: UploadType::UploadByVideoId,
+            video_id: Some(video_id.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    pub fn video_signature(mut self, value: &str) -> Self {
+        self.video_signature = Some(value.to_owned());
+        self
+    }
+
+    pub fn file_name(mut self, value: &str) -> Self {
+        self.file_name = Some(value.to_owned());
+        self
+    }
+
+    pub fn is_third_party(mut self, value: bool) -> Self {
+        self.is_third_party = Some(value);
+        self
+    }
+
+    pub fn flaw_detect(mut self, value: bool) -> Self {
+        self.flaw_detect = Some(value);
+        self
+    }
+
+    pub fn auto_fix_enabled(mut self, value: bool) -> Self {
+        self.auto_fix_enabled = Some(value);
+        self
+    }
+
+    pub fn auto_bind_enabled(mut self, value: bool) -> Self {
+        self.auto_bind_enabled = Some(value);
+        self
+    }
+
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut form = Form::new()
+            .text("advertiser_id", self.advertiser_id)
+            .text("upload_type", self.upload_type.to_string());
+        if let Some(video_file) = self.video_file {
+            form = form.part("video_file", file_part(&video_file, self.video_file_size));
+        }
+        if let Some(video_signature) = self.video_signature {
+            form = form.text("video_signature", video_signature);
+        }
+        if let Some(video_url) = self.video_url {
+            form = form.text("video_url", video_url);
+        }
+        if let Some(file_id) = self.file_id {
+            form = form.text("file_id", file_id);
+        }
+        if let Some(video_id) = self.video_id {
+            form = form.text("video_id", video_id);
+        }
+        if let Some(file_name) = self.file_name {
+            form = form.text("file_name", file_name);
+        }
+        if let Some(is_third_party) = self.is_third_party {
+            form = form.text("is_third_party", is_third_party.to_string());
+        }
+        if let Some(flaw_detect) = self.flaw_detect {
+            form = form.text("flaw_detect", flaw_detect.to_string());
+        }
+        if let Some(auto_fix_enabled) = self.auto_fix_enabled {
+            form = form.text("auto_fix_enabled", auto_fix_enabled.to_string());
+        }
+        if let Some(auto_bind_enabled) = self.auto_bind_enabled {
+            form = form.text("auto_bind_enabled", auto_bind_enabled.to_string());
+        }
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .multipart(form)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(mut self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        if let Some(video_file) = self.video_file.as_ref() {
+            let (signature, size) = file_md5(video_file).await?;
+            self.video_signature.get_or_insert(signature);
+            self.video_file_size = Some(size);
+        }
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<AdVideo>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,632 @@
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/optimizer/rule/create/";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum NotificationType {
+    #[serde(rename = "NOT_NOTIFY")]
+    #[default]
+    NotNotify,
+    #[serde(rename = "ANY_CHANGES")]
+    AnyChanges,
+    #[serde(rename = "TASK_FINISH")]
+    TaskFinish,
+}
+
+impl std::fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NotNotify => write!(f, "NOT_NOTIFY"),
+            Self::AnyChanges => write!(f, "ANY_CHANGES"),
+            Self::TaskFinish => write!(f, "TASK_FINISH"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum NotificationPeriod {
+    #[serde(rename = "EVERY_TIME")]
+    #[default]
+    EveryTime,
+    #[serde(rename = "DAILY")]
+    Daily,
+}
+
+impl std::fmt::Display for NotificationPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::EveryTime => write!(f, "EVERY_TIME"),
+            Self::Daily => write!(f, "DAILY"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ExecTimeType {
+    #[serde(rename = "PER_HALF_HOUR")]
+    #[default]
+    PerHalfHour,
+    #[serde(rename = "PER_ONE_HOUR")]
+    PerOneHour,
+    #[serde(rename = "PER_ONE_DAY")]
+    PerOneDay,
+    #[serde(rename = "SPECIFIC_TIME")]
+    SpecificTime,
+}
+
+impl std::fmt::Display for ExecTimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PerHalfHour => write!(f, "PER_HALF_HOUR"),
+            Self::PerOneHour => write!(f, "PER_ONE_HOUR"),
+            Self::PerOneDay => write!(f, "PER_ONE_DAY"),
+            Self::SpecificTime => write!(f, "SPECIFIC_TIME"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ValueType {
+    #[serde(rename = "EXACT")]
+    #[default]
+    Exact,
+    #[serde(rename = "PERCENT")]
+    Percent,
+}
+
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Exact => write!(f, "EXACT"),
+            Self::Percent => write!(f, "PERCENT"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ActionType {
+    #[serde(rename = "INCREASE")]
+    #[default]
+    Increase,
+    #[serde(rename = "DECREASE")]
+    Decrease,
+    #[serde(rename = "ADJUST_TO")]
+    AdjustTo,
+}
+
+impl std::fmt::Display for ActionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Increase => write!(f, "INCREASE"),
+            Self::Decrease => write!(f, "DECREASE"),
+            Self::AdjustTo => write!(f, "ADJUST_TO"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ActionSubject {
+    #[serde(rename = "TURN_OFF")]
+    #[default]
+    TurnOff,
+    #[serde(rename = "TURN_ON")]
+    TurnOn,
+    #[serde(rename = "BUDGET")]
+    Budget,
+    #[serde(rename = "BID")]
+    Bid,
+    #[serde(rename = "MESSAGE")]
+    Message,
+}
+
+impl std::fmt::Display for ActionSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::TurnOff => write!(f, "TURN_OFF"),
+            Self::TurnOn => write!(f, "TURN_ON"),
+            Self::Budget => write!(f, "BUDGET"),
+            Self::Bid => write!(f, "BID"),
+            Self::Message => write!(f, "MESSAGE"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum CustomFrequencyType {
+    #[serde(rename = "N_MINUTES_ONCE")]
+    #[default]
+    NMinutesOnce,
+    #[serde(rename = "N_HOURS_ONCE")]
+    NHoursOnce,
+    #[serde(rename = "N_DAYS_ONCE")]
+    NDaysOnce,
+}
+
+impl std::fmt::Display for CustomFrequencyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NMinutesOnce => write!(f, "N_MINUTES_ONCE"),
+            Self::NHoursOnce => write!(f, "N_HOURS_ONCE"),
+            Self::NDaysOnce => write!(f, "N_DAYS_ONCE"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum FrequencyType {
+    #[serde(rename = "ONLY_ONCE")]
+    #[default]
+    OnlyOnce,
+    #[serde(rename = "EVERY_RUN")]
+    EveryRun,
+    #[serde(rename = "CUSTOM")]
+    Custom,
+}
+
+impl std::fmt::Display for FrequencyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::OnlyOnce => write!(f, "ONLY_ONCE"),
+            Self::EveryRun => write!(f, "EVERY_RUN"),
+            Self::Custom => write!(f, "CUSTOM"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum MatchType {
+    #[serde(rename = "GT")]
+    #[default]
+    Gt,
+    #[serde(rename = "GTE")]
+    Gte,
+    #[serde(rename = "LT")]
+    Lt,
+    #[serde(rename = "LTE")]
+    Lte,
+    #[serde(rename = "EQ")]
+    Eq,
+    #[serde(rename = "BETWEEN")]
+    Between,
+}
+
+impl std::fmt::Display for MatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Gt => write!(f, "GT"),
+            Self::Gte => write!(f, "GTE"),
+            Self::Lt => write!(f, "LT"),
+            Self::Lte => write!(f, "LTE"),
+            Self::Eq => write!(f, "EQ"),
+            Self::Between => write!(f, "BETWEEN"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum RangeType {
+    #[serde(rename = "TODAY")]
+    #[default]
+    Today,
+    #[serde(rename = "YESTERDAY")]
+    Yesterday,
+    #[serde(rename = "LAST_THREE_DAYS")]
+    LastThreeDays,
+    #[serde(rename = "LAST_SEVEN_DAYS")]
+    LastSevenDays,
+    #[serde(rename = "LAST_FOURTEEN_DAYS")]
+    LastFourteenDays,
+    #[serde(rename = "LAST_THIRTY_DAYS")]
+    LastThirtyDays,
+    #[serde(rename = "LIFETIME")]
+    Lifetime,
+}
+
+impl std::fmt::Display for RangeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Today => write!(f, "TODAY"),
+            Self::Yesterday => write!(f, "YESTERDAY"),
+            Self::LastThreeDays => write!(f, "LAST_THREE_DAYS"),
+            Self::LastSevenDays => write!(f, "LAST_SEVEN_DAYS"),
+            Self::LastFourteenDays => write!(f, "LAST_FOURTEEN_DAYS"),
+            Self::LastThirtyDays => write!(f, "LAST_THIRTY_DAYS"),
+            Self::Lifetime => write!(f, "LIFETIME"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ConditionSubject {
+    #[serde(rename = "SPEND")]
+    #[default]
+    Spend,
+    #[serde(rename = "DAILY_BUDGET_SPENDING_RATE")]
+    DailyBudgetSpendingRate,
+    #[serde(rename = "LIFETIME_BUDGET_SPENDING_RATE")]
+    LifetimeBudgetSpendingRate,
+    #[serde(rename = "IMPRESSIONS")]
+    Impressions,
+    #[serde(rename = "CLICKS")]
+    Clicks,
+    #[serde(rename = "CTR")]
+    Ctr,
+    #[serde(rename = "CPC")]
+    Cpc,
+    #[serde(rename = "CPM")]
+    Cpm,
+    #[serde(rename = "CONVERSIONS")]
+    Conversions,
+    #[serde(rename = "CPA")]
+    Cpa,
+    #[serde(rename = "CVR")]
+    Cvr,
+    #[serde(rename = "FREQUENCY")]
+    Frequency,
+}
+
+impl std::fmt::Display for ConditionSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Spend => write!(f, "SPEND"),
+            Self::DailyBudgetSpendingRate => write!(f, "DAILY_BUDGET_SPENDING_RATE"),
+            Self::LifetimeBudgetSpendingRate => write!(f, "LIFETIME_BUDGET_SPENDING_RATE"),
+            Self::Impressions => write!(f, "IMPRESSIONS"),
+            Self::Clicks => write!(f, "CLICKS"),
+            Self::Ctr => write!(f, "CTR"),
+            Self::Cpc => write!(f, "CPC"),
+            Self::Cpm => write!(f, "CPM"),
+            Self::Conversions => write!(f, "CONVERSIONS"),
+            Self::Cpa => write!(f, "CPA"),
+            Self::Cvr => write!(f, "CVR"),
+            Self::Frequency => write!(f, "FREQUENCY"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum PreConditionType {
+    #[serde(rename = "SELECTED")]
+    #[default]
+    Selected,
+    #[serde(rename = "ALL_ACTIVE_CAMPAIGN")]
+    AllActiveCampaign,
+    #[serde(rename = "ALL_ACTIVE_ADGROUP")]
+    AllActiveAdgroup,
+    #[serde(rename = "ALL_ACTIVE_AD")]
+    AllActiveAd,
+}
+
+impl std::fmt::Display for PreConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Selected => write!(f, "SELECTED"),
+            Self::AllActiveCampaign => write!(f, "ALL_ACTIVE_CAMPAIGN"),
+            Self::AllActiveAdgroup => write!(f, "ALL_ACTIVE_ADGROUP"),
+            Self::AllActiveAd => write!(f, "ALL_ACTIVE_AD"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Dimension {
+    #[serde(rename = "CAMPAIGN")]
+    #[default]
+    Campaign,
+    #[serde(rename = "ADGROUP")]
+    Adgroup,
+    #[serde(rename = "AD")]
+    Ad,
+}
+
+impl std::fmt::Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Campaign => write!(f, "CAMPAIGN"),
+            Self::Adgroup => write!(f, "ADGROUP"),
+            Self::Ad => write!(f, "AD"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ApplyObject {
+    pub dimension: Dimension,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension_ids: Option<Vec<String>>,
+    pub pre_condition_type: PreConditionType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Condition {
+    pub subject_type: ConditionSubject,
+    pub range_type: RangeType,
+    pub match_type: MatchType,
+    pub values: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct FrequencyInfo {
+    pub r#type: FrequencyType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_frequency_type: Option<CustomFrequencyType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Action {
+    pub subject_type: ActionSubject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<ActionType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<ValueType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_info: Option<FrequencyInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct RuleExecInfo {
+    pub exec_time_type: ExecTimeType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec_time: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct EmailSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_period: Option<NotificationPeriod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_exec_time: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Notification {
+    pub notification_type: NotificationType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_setting: Option<EmailSetting>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Rule {
+    pub name: String,
+    pub apply_objects: Vec<ApplyObject>,
+    pub conditions: Vec<Condition>,
+    pub actions: Vec<Action>,
+    pub rule_exec_info: RuleExecInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification: Option<Notification>,
+}
+
+impl ApplyObject {
+    pub fn selected(dimension: Dimension, ids: &[&str]) -> Self {
+        Self {
+            dimension,
+            dimension_ids: Some(ids.iter().map(|it| it.to_string()).collect()),
+            pre_condition_type: PreConditionType::Selected,
+        }
+    }
+
+    pub fn all_active(dimension: Dimension) -> Self {
+        let pre_condition_type = match dimension {
+            Dimension::Campaign => PreConditionType::AllActiveCampaign,
+            Dimension::Adgroup => PreConditionType::AllActiveAdgroup,
+            Dimension::Ad => PreConditionType::AllActiveAd,
+        };
+        Self {
+            dimension,
+            dimension_ids: None,
+            pre_condition_type,
+        }
+    }
+}
+
+impl Condition {
+    pub fn new(
+        subject_type: ConditionSubject,
+        range_type: RangeType,
+        match_type: MatchType,
+        value: f64,
+    ) -> Self {
+        Self {
+            subject_type,
+            range_type,
+            match_type,
+            values: vec![value.to_string()],
+        }
+    }
+
+    // BETWEEN の場合は下限と上限を指定する
+    pub fn between(
+        subject_type: ConditionSubject,
+        range_type: RangeType,
+        min: f64,
+        max: f64,
+    ) -> Self {
+        Self {
+            subject_type,
+            range_type,
+            match_type: MatchType::Between,
+            values: vec![min.to_string(), max.to_string()],
+        }
+    }
+}
+
+impl Action {
+    pub fn turn_off() -> Self {
+        Self {
+            subject_type: ActionSubject::TurnOff,
+            ..Default::default()
+        }
+    }
+
+    pub fn turn_on() -> Self {
+        Self {
+            subject_type: ActionSubject::TurnOn,
+            ..Default::default()
+        }
+    }
+
+    // 予算や入札額の調整。limit は増減後の上限(下限)
+    pub fn adjust(
+        subject_type: ActionSubject,
+        action_type: ActionType,
+        value_type: ValueType,
+        value: f64,
+        limit: Option<f64>,
+    ) -> Self {
+        Self {
+            subject_type,
+            action_type: Some(action_type),
+            value_type: Some(value_type),
+            value: Some(value),
+            limit,
+            frequency_info: None,
+        }
+    }
+
+    pub fn frequency_info(mut self, value: FrequencyInfo) -> Self {
+        self.frequency_info = Some(value);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub advertiser_id: String,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(body: Body, options: Option<TiktokOptions>) -> Self {
+        Self { options, body }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .json(&self.body)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_ids: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_optimizer_rule_create_body -- --nocapture --test-threads=1
+    #[test]
+    fn test_optimizer_rule_create_body() -> anyhow::Result<()> {
+        let rule = Rule {
+            name: "pause high cpa".to_owned(),
+            apply_objects: vec![ApplyObject::all_active(Dimension::Adgroup)],
+            conditions: vec![Condition::new(
+                ConditionSubject::Cpa,
+                RangeType::Today,
+                MatchType::Gt,
+                12.5,
+            )],
+            actions: vec![
+                Action::turn_off(),
+                Action::adjust(
+                    ActionSubject::Budget,
+                    ActionType::Increase,
+                    ValueType::Percent,
+                    20.0,
+                    Some(500.0),
+                )
+                .frequency_info(FrequencyInfo {
+                    r#type: FrequencyType::OnlyOnce,
+                    ..Default::default()
+                }),
+            ],
+            rule_exec_info: RuleExecInfo {
+                exec_time_type: ExecTimeType::PerOneHour,
+                exec_time: None,
+            },
+            notification: None,
+        };
+        let value = serde_json::to_value(&rule)?;
+        assert_eq!(
+            value["apply_objects"][0]["pre_condition_type"],
+            "ALL_ACTIVE_ADGROUP"
+        );
+        assert_eq!(value["conditions"][0]["subject_type"], "CPA");
+        assert_eq!(value["conditions"][0]["match_type"], "GT");
+        assert_eq!(value["conditions"][0]["values"][0], "12.5");
+        assert_eq!(
+            value["actions"][0],
+            serde_json::json!({"subject_type": "TURN_OFF"})
+        );
+        assert_eq!(value["actions"][1]["action_type"], "INCREASE");
+        assert_eq!(value["actions"][1]["value_type"], "PERCENT");
+        assert_eq!(value["actions"][1]["frequency_info"]["type"], "ONLY_ONCE");
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::apis::post_optimizer_rule_create::Action;
+use crate::apis::post_optimizer_rule_create::ApplyObject;
+use crate::apis::post_optimizer_rule_create::Condition;
+use crate::apis::post_optimizer_rule_create::Notification;
+use crate::apis::post_optimizer_rule_create::Rule;
+use crate::apis::post_optimizer_rule_create::RuleExecInfo;
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/optimizer/rule/update/";
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct UpdateRule {
+    pub rule_id: String,
+    pub name: String,
+    pub apply_objects: Vec<ApplyObject>,
+    pub conditions: Vec<Condition>,
+    pub actions: Vec<Action>,
+    pub rule_exec_info: RuleExecInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification: Option<Notification>,
+}
+
+impl UpdateRule {
+    // 作成時と同じ Rule を rule_id 付きで送る
+    pub fn new(rule_id: &str, rule: Rule) -> Self {
+        Self {
+            rule_id: rule_id.to_owned(),
+            name: rule.name,
+            apply_objects: rule.apply_objects,
+            conditions: rule.conditions,
+            actions: rule.actions,
+            rule_exec_info: rule.rule_exec_info,
+            notification: rule.notification,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub advertiser_id: String,
+    pub rules: Vec<UpdateRule>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(body: Body, options: Option<TiktokOptions>) -> Self {
+        Self { options, body }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .json(&self.body)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_ids: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
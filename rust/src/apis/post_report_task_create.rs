@@ -0,0 +1,190 @@
+use crate::apis::get_report_integrated_get::{DataLevel, Filter, OrderType, ReportType};
+use crate::responses::{
+    report_dimensions::ReportDimensionsField, report_metrics::ReportMetricsField,
+};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const URL: &str = "/report/task/create/";
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+struct Body {
+    advertiser_id: String,
+    report_type: ReportType,
+    dimensions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_level: Option<DataLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metrics: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_lifetime: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filtering: Option<Vec<Filter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_type: Option<OrderType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    report_type: ReportType,
+    dimensions: HashSet<ReportDimensionsField>,
+    data_level: Option<DataLevel>,
+    metrics: Option<HashSet<ReportMetricsField>>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    query_lifetime: Option<bool>,
+    filtering: Option<Vec<Filter>>,
+    order_field: Option<String>,
+    order_type: Option<OrderType>,
+    file_name: Option<String>,
+}
+
+impl Api {
+    pub fn new(
+        advertiser_id: &str,
+        report_type: ReportType,
+        dimensions: HashSet<ReportDimensionsField>,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            report_type,
+            dimensions,
+            ..Default::default()
+        }
+    }
+
+    pub fn data_level(mut self, value: DataLevel) -> Self {
+        self.data_level = Some(value);
+        self
+    }
+
+    pub fn metrics(mut self, value: HashSet<ReportMetricsField>) -> Self {
+        self.metrics = Some(value);
+        self
+    }
+
+    pub fn start_date(mut self, value: &str) -> Self {
+        self.start_date = Some(value.to_owned());
+        self
+    }
+
+    pub fn end_date(mut self, value: &str) -> Self {
+        self.end_date = Some(value.to_owned());
+        self
+    }
+
+    pub fn query_lifetime(mut self, value: bool) -> Self {
+        self.query_lifetime = Some(value);
+        self
+    }
+
+    pub fn filtering(mut self, value: Vec<Filter>) -> Self {
+        self.filtering = Some(value);
+        self
+    }
+
+    pub fn order_field(mut self, value: &str) -> Self {
+        self.order_field = Some(value.to_owned());
+        self
+    }
+
+    pub fn order_type(mut self, value: OrderType) -> Self {
+        self.order_type = Some(value);
+        self
+    }
+
+    pub fn file_name(mut self, value: &str) -> Self {
+        self.file_name = Some(value.to_owned());
+        self
+    }
+
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let body = Body {
+            advertiser_id: self.advertiser_id,
+            report_type: self.report_type,
+            dimensions: self.dimensions.iter().map(|it| it.to_string()).collect(),
+            data_level: self.data_level,
+            metrics: self
+                .metrics
+                .map(|it| it.iter().map(|item| item.to_string()).collect()),
+            start_date: self.start_date,
+            end_date: self.end_date,
+            query_lifetime: self.query_lifetime,
+            filtering: self.filtering,
+            order_field: self.order_field,
+            order_type: self.order_type,
+            file_name: self.file_name,
+        };
+        let client = reqwest::Client::new()
+            .post(make_url(URL, &self.options))
+            .json(&body)
+            .header("Access-Token", bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    pub request_id: String,
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
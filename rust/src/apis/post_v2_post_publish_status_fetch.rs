@@ -0,0 +1,147 @@
+use crate::responses::open_api_error::{OpenApiError, check_open_api_error};
+use crate::{
+    apis::{ApiResponse, execute_api, poll_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_open_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/v2/post/publish/status/fetch/";
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub publish_id: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(body: Body, options: Option<TiktokOptions>) -> Self {
+        Self { options, body }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .post(make_open_url(URL, &self.options))
+            .json(&self.body)
+            .bearer_auth(bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    pub async fn execute_until_done(self, bearer_code: &str) -> Result<Data, ApiError> {
+        let api = &self;
+        poll_api(
+            || async move {
+                let res = api.clone().execute(bearer_code).await?;
+                check_open_api_error(res.body.error, res.status_code)?;
+                let Some(data) = res.body.data else {
+                    return Ok(None);
+                };
+                match data.status {
+                    Some(Status::PublishComplete) | Some(Status::SendToUserInbox) => Ok(Some(data)),
+                    Some(Status::Failed) => Err(ApiError::Invalid(format!(
+                        "publish {} {}",
+                        api.body.publish_id,
+                        data.fail_reason.as_deref().unwrap_or_default()
+                    ))),
+                    _ => Ok(None),
+                }
+            },
+            &self.options,
+        )
+        .await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<OpenApiError>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .error
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publicaly_available_post_id: Option<Vec<i64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uploaded_bytes: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloaded_bytes: Option<i64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Status {
+    #[serde(rename = "PROCESSING_UPLOAD")]
+    #[default]
+    ProcessingUpload,
+    #[serde(rename = "PROCESSING_DOWNLOAD")]
+    ProcessingDownload,
+    #[serde(rename = "SEND_TO_USER_INBOX")]
+    SendToUserInbox,
+    #[serde(rename = "PUBLISH_COMPLETE")]
+    PublishComplete,
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ProcessingUpload => write!(f, "PROCESSING_UPLOAD"),
+            Self::ProcessingDownload => write!(f, "PROCESSING_DOWNLOAD"),
+            Self::SendToUserInbox => write!(f, "SEND_TO_USER_INBOX"),
+            Self::PublishComplete => write!(f, "PUBLISH_COMPLETE"),
+            Self::Failed => write!(f, "FAILED"),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::responses::open_api_error::{OpenApiError, check_open_api_error};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_open_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+const URL: &str = "/v2/post/publish/video/init/";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Source {
+    #[serde(rename = "FILE_UPLOAD")]
+    #[default]
+    FileUpload,
+    #[serde(rename = "PULL_FROM_URL")]
+    PullFromUrl,
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::FileUpload => write!(f, "FILE_UPLOAD"),
+            Self::PullFromUrl => write!(f, "PULL_FROM_URL"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum PrivacyLevel {
+    #[serde(rename = "PUBLIC_TO_EVERYONE")]
+    #[default]
+    PublicToEveryone,
+    #[serde(rename = "MUTUAL_FOLLOW_FRIENDS")]
+    MutualFollowFriends,
+    #[serde(rename = "FOLLOWER_OF_CREATOR")]
+    FollowerOfCreator,
+    #[serde(rename = "SELF_ONLY")]
+    SelfOnly,
+}
+
+impl std::fmt::Display for PrivacyLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PublicToEveryone => write!(f, "PUBLIC_TO_EVERYONE"),
+            Self::MutualFollowFriends => write!(f, "MUTUAL_FOLLOW_FRIENDS"),
+            Self::FollowerOfCreator => write!(f, "FOLLOWER_OF_CREATOR"),
+            Self::SelfOnly => write!(f, "SELF_ONLY"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct PostInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub privacy_level: PrivacyLevel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_duet: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_comment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_stitch: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_cover_timestamp_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_content_toggle: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_organic_toggle: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_aigc: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct SourceInfo {
+    pub source: Source,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_chunk_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_url: Option<String>,
+}
+
+// チャンクの分割は upload::ChunkPlan で計算した値を渡す
+impl SourceInfo {
+    pub fn file_upload(video_size: u64, chunk_size: u64, total_chunk_count: u64) -> Self {
+        Self {
+            source: Source::FileUpload,
+            video_size: Some(video_size as i64),
+            chunk_size: Some(chunk_size as i64),
+            total_chunk_count: Some(total_chunk_count as i64),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_info: Option<PostInfo>,
+    pub source_info: SourceInfo,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(body: Body, options: Option<TiktokOptions>) -> Self {
+        Self { options, body }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .post(make_open_url(URL, &self.options))
+            .json(&self.body)
+            .bearer_auth(bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // error.code が "ok" 以外ならエラーにして publish_id と upload_url を返す
+    pub async fn execute_data(self, bearer_code: &str) -> Result<Data, ApiError> {
+        let res = self.execute(bearer_code).await?;
+        check_open_api_error(res.body.error, res.status_code)?;
+        res.body
+            .data
+            .ok_or_else(|| ApiError::Invalid("publish init data not found".to_owned()))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<OpenApiError>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .error
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,121 @@
+use crate::responses::{
+    adlib_ad::AdlibAd, adlib_advertiser::AdlibAdvertiser, open_api_error::OpenApiError,
+};
+use crate::responses::{adlib_ad::AdlibAdField, adlib_advertiser::AdlibAdvertiserField};
+use crate::{
+    apis::{ApiResponse, execute_api, post_v2_research_adlib_ad_query::make_fields},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_open_url},
+};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const URL: &str = "/v2/research/adlib/ad/detail/";
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub ad_id: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    fields: HashSet<AdlibAdField>,
+    advertiser_fields: HashSet<AdlibAdvertiserField>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(
+        fields: HashSet<AdlibAdField>,
+        advertiser_fields: HashSet<AdlibAdvertiserField>,
+        body: Body,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            fields,
+            advertiser_fields,
+            body,
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("fields", make_fields(&self.fields, &self.advertiser_fields)));
+        let client = reqwest::Client::new()
+            .post(make_open_url(URL, &self.options))
+            .query(&query_parameters)
+            .json(&self.body)
+            .bearer_auth(bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<OpenApiError>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .error
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad: Option<AdlibAd>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser: Option<AdlibAdvertiser>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_group: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .ad
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .advertiser
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
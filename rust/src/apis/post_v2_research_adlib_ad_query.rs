@@ -0,0 +1,271 @@
+use crate::responses::{
+    adlib_ad::AdlibAd,
+    adlib_advertiser::AdlibAdvertiser,
+    open_api_error::{OpenApiError, check_open_api_error},
+};
+use crate::responses::{adlib_ad::AdlibAdField, adlib_advertiser::AdlibAdvertiserField};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_open_url},
+};
+use chrono::NaiveDate;
+use itertools::Itertools;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const URL: &str = "/v2/research/adlib/ad/query/";
+const DATE_FORMAT: &str = "%Y%m%d";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum SearchType {
+    #[serde(rename = "exact_phrase")]
+    #[default]
+    ExactPhrase,
+    #[serde(rename = "fuzzy_phrase")]
+    FuzzyPhrase,
+}
+
+impl std::fmt::Display for SearchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ExactPhrase => write!(f, "exact_phrase"),
+            Self::FuzzyPhrase => write!(f, "fuzzy_phrase"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct DateRange {
+    pub min: String,
+    pub max: String,
+}
+
+impl DateRange {
+    pub fn new(min: NaiveDate, max: NaiveDate) -> Result<Self, ApiError> {
+        if max < min {
+            return Err(ApiError::Invalid(format!("date range {} - {}", min, max)));
+        }
+        Ok(Self {
+            min: min.format(DATE_FORMAT).to_string(),
+            max: max.format(DATE_FORMAT).to_string(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct UniqueUsersSeenSizeRange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Filters {
+    pub ad_published_date_range: DateRange,
+    pub country_code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_business_ids: Option<Vec<i64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_users_seen_size_range: Option<UniqueUsersSeenSizeRange>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub filters: Filters,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_term: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_type: Option<SearchType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    fields: HashSet<AdlibAdField>,
+    advertiser_fields: HashSet<AdlibAdvertiserField>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(
+        fields: HashSet<AdlibAdField>,
+        advertiser_fields: HashSet<AdlibAdvertiserField>,
+        body: Body,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            fields,
+            advertiser_fields,
+            body,
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("fields", make_fields(&self.fields, &self.advertiser_fields)));
+        let client = reqwest::Client::new()
+            .post(make_open_url(URL, &self.options))
+            .query(&query_parameters)
+            .json(&self.body)
+            .bearer_auth(bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // search_id を引き継いで has_more が false になるまで取得する
+    pub async fn execute_all(self, bearer_code: &str) -> Result<Vec<AdResult>, ApiError> {
+        let mut items = vec![];
+        let mut api = self;
+        loop {
+            let res = api.clone().execute(bearer_code).await?;
+            check_open_api_error(res.body.error, res.status_code)?;
+            let Some(data) = res.body.data else {
+                break;
+            };
+            items.extend(data.ads.unwrap_or_default());
+            if !data.has_more.unwrap_or(false) || data.search_id.is_none() {
+                break;
+            }
+            api.body.search_id = data.search_id;
+        }
+        Ok(items)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<OpenApiError>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .error
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ads: Option<Vec<AdResult>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .ads
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad: Option<AdlibAd>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser: Option<AdlibAdvertiser>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdResult {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .ad
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .advertiser
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("AdResult {:?}", self.extra);
+        }
+        res
+    }
+}
+
+// fields は ad.id, advertiser.business_id のように親の名前を付けて渡す
+pub(crate) fn make_fields(
+    fields: &HashSet<AdlibAdField>,
+    advertiser_fields: &HashSet<AdlibAdvertiserField>,
+) -> String {
+    fields
+        .iter()
+        .map(|it| format!("ad.{}", it))
+        .chain(
+            advertiser_fields
+                .iter()
+                .map(|it| format!("advertiser.{}", it)),
+        )
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_adlib_ad_query_make_fields -- --nocapture --test-threads=1
+    #[test]
+    fn test_adlib_ad_query_make_fields() -> anyhow::Result<()> {
+        let fields = make_fields(
+            &HashSet::from([AdlibAdField::Id]),
+            &HashSet::from([AdlibAdvertiserField::BusinessName]),
+        );
+        assert_eq!(fields, "ad.id,advertiser.business_name");
+
+        let min = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+        let max = NaiveDate::from_ymd_opt(2026, 1, 9).unwrap();
+        let range = DateRange::new(min, max)?;
+        assert_eq!(range.min, "20260102");
+        assert_eq!(range.max, "20260109");
+        assert!(DateRange::new(max, min).is_err());
+        Ok(())
+    }
+}
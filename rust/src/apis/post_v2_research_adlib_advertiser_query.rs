@@ -0,0 +1,138 @@
+use crate::responses::adlib_advertiser::AdlibAdvertiserField;
+use crate::responses::{
+    adlib_advertiser::AdlibAdvertiser,
+    open_api_error::{OpenApiError, check_open_api_error},
+};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_open_url},
+};
+use itertools::Itertools;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const URL: &str = "/v2/research/adlib/advertiser/query/";
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub search_term: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    fields: HashSet<AdlibAdvertiserField>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(
+        fields: HashSet<AdlibAdvertiserField>,
+        body: Body,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            fields,
+            body,
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("fields", self.fields.iter().join(",")));
+        let client = reqwest::Client::new()
+            .post(make_open_url(URL, &self.options))
+            .query(&query_parameters)
+            .json(&self.body)
+            .bearer_auth(bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // search_id を引き継いで has_more が false になるまで取得する
+    pub async fn execute_all(self, bearer_code: &str) -> Result<Vec<AdlibAdvertiser>, ApiError> {
+        let mut items = vec![];
+        let mut api = self;
+        loop {
+            let res = api.clone().execute(bearer_code).await?;
+            check_open_api_error(res.body.error, res.status_code)?;
+            let Some(data) = res.body.data else {
+                break;
+            };
+            items.extend(data.advertisers.unwrap_or_default());
+            if !data.has_more.unwrap_or(false) || data.search_id.is_none() {
+                break;
+            }
+            api.body.search_id = data.search_id;
+        }
+        Ok(items)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<OpenApiError>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .error
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertisers: Option<Vec<AdlibAdvertiser>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .advertisers
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,403 @@
+use crate::responses::research_video::ResearchVideoField;
+use crate::responses::{
+    open_api_error::{OpenApiError, check_open_api_error},
+    research_video::ResearchVideo,
+};
+use crate::{
+    apis::{ApiResponse, execute_api},
+    error::Error as ApiError,
+    options::{TiktokOptions, apply_timeout, make_open_url},
+};
+use chrono::NaiveDate;
+use itertools::Itertools;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const URL: &str = "/v2/research/video/query/";
+const DATE_FORMAT: &str = "%Y%m%d";
+pub const MAX_DATE_RANGE_DAYS: i64 = 30;
+pub const MAX_COUNT: i64 = 100;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum FieldName {
+    #[serde(rename = "create_date")]
+    #[default]
+    CreateDate,
+    #[serde(rename = "username")]
+    Username,
+    #[serde(rename = "region_code")]
+    RegionCode,
+    #[serde(rename = "video_id")]
+    VideoId,
+    #[serde(rename = "hashtag_name")]
+    HashtagName,
+    #[serde(rename = "keyword")]
+    Keyword,
+    #[serde(rename = "music_id")]
+    MusicId,
+    #[serde(rename = "effect_id")]
+    EffectId,
+    #[serde(rename = "video_length")]
+    VideoLength,
+}
+
+impl std::fmt::Display for FieldName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::CreateDate => write!(f, "create_date"),
+            Self::Username => write!(f, "username"),
+            Self::RegionCode => write!(f, "region_code"),
+            Self::VideoId => write!(f, "video_id"),
+            Self::HashtagName => write!(f, "hashtag_name"),
+            Self::Keyword => write!(f, "keyword"),
+            Self::MusicId => write!(f, "music_id"),
+            Self::EffectId => write!(f, "effect_id"),
+            Self::VideoLength => write!(f, "video_length"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Operation {
+    #[serde(rename = "EQ")]
+    #[default]
+    Eq,
+    #[serde(rename = "IN")]
+    In,
+    #[serde(rename = "GT")]
+    Gt,
+    #[serde(rename = "GTE")]
+    Gte,
+    #[serde(rename = "LT")]
+    Lt,
+    #[serde(rename = "LTE")]
+    Lte,
+}
+
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Eq => write!(f, "EQ"),
+            Self::In => write!(f, "IN"),
+            Self::Gt => write!(f, "GT"),
+            Self::Gte => write!(f, "GTE"),
+            Self::Lt => write!(f, "LT"),
+            Self::Lte => write!(f, "LTE"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Condition {
+    pub operation: Operation,
+    pub field_name: FieldName,
+    pub field_values: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Query {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<Condition>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<Condition>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not: Option<Vec<Condition>>,
+}
+
+impl Condition {
+    pub fn new(operation: Operation, field_name: FieldName, values: &[&str]) -> Self {
+        Self {
+            operation,
+            field_name,
+            field_values: values.iter().map(|it| it.to_string()).collect(),
+        }
+    }
+
+    pub fn eq(field_name: FieldName, value: &str) -> Self {
+        Self::new(Operation::Eq, field_name, &[value])
+    }
+
+    pub fn is_in(field_name: FieldName, values: &[&str]) -> Self {
+        Self::new(Operation::In, field_name, values)
+    }
+
+    pub fn gt(field_name: FieldName, value: &str) -> Self {
+        Self::new(Operation::Gt, field_name, &[value])
+    }
+
+    pub fn gte(field_name: FieldName, value: &str) -> Self {
+        Self::new(Operation::Gte, field_name, &[value])
+    }
+
+    pub fn lt(field_name: FieldName, value: &str) -> Self {
+        Self::new(Operation::Lt, field_name, &[value])
+    }
+
+    pub fn lte(field_name: FieldName, value: &str) -> Self {
+        Self::new(Operation::Lte, field_name, &[value])
+    }
+}
+
+// and / or / not はそれぞれ条件を積み上げていく
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn and(mut self, condition: Condition) -> Self {
+        self.and.get_or_insert_with(Vec::new).push(condition);
+        self
+    }
+
+    pub fn or(mut self, condition: Condition) -> Self {
+        self.or.get_or_insert_with(Vec::new).push(condition);
+        self
+    }
+
+    pub fn not(mut self, condition: Condition) -> Self {
+        self.not.get_or_insert_with(Vec::new).push(condition);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        [&self.and, &self.or, &self.not]
+            .iter()
+            .all(|it| it.as_ref().map(|it| it.is_empty()).unwrap_or(true))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Body {
+    pub query: Query,
+    pub start_date: String,
+    pub end_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_random: Option<bool>,
+}
+
+impl Body {
+    pub fn new(query: Query, start_date: NaiveDate, end_date: NaiveDate) -> Result<Self, ApiError> {
+        let body = Self {
+            query,
+            start_date: start_date.format(DATE_FORMAT).to_string(),
+            end_date: end_date.format(DATE_FORMAT).to_string(),
+            ..Default::default()
+        };
+        body.validate()?;
+        Ok(body)
+    }
+
+    pub fn max_count(mut self, value: i64) -> Self {
+        self.max_count = Some(value);
+        self
+    }
+
+    pub fn is_random(mut self, value: bool) -> Self {
+        self.is_random = Some(value);
+        self
+    }
+
+    // 期間は end_date が start_date から30日以内である必要がある
+    pub fn validate(&self) -> Result<(), ApiError> {
+        if self.query.is_empty() {
+            return Err(ApiError::Invalid("query is empty".to_owned()));
+        }
+        let start_date = parse_date("start_date", &self.start_date)?;
+        let end_date = parse_date("end_date", &self.end_date)?;
+        if end_date < start_date {
+            return Err(ApiError::Invalid(format!(
+                "end_date {} is before start_date {}",
+                self.end_date, self.start_date
+            )));
+        }
+        if (end_date - start_date).num_days() > MAX_DATE_RANGE_DAYS {
+            return Err(ApiError::Invalid(format!(
+                "date range {} - {} exceeds {} days",
+                self.start_date, self.end_date, MAX_DATE_RANGE_DAYS
+            )));
+        }
+        if let Some(max_count) = self.max_count
+            && !(1..=MAX_COUNT).contains(&max_count)
+        {
+            return Err(ApiError::Invalid(format!("max_count {}", max_count)));
+        }
+        Ok(())
+    }
+}
+
+fn parse_date(name: &str, value: &str) -> Result<NaiveDate, ApiError> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT)
+        .map_err(|_| ApiError::Invalid(format!("{} {}", name, value)))
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Api {
+    options: Option<TiktokOptions>,
+    fields: HashSet<ResearchVideoField>,
+    body: Body,
+}
+
+impl Api {
+    pub fn new(
+        fields: HashSet<ResearchVideoField>,
+        body: Body,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            fields,
+            body,
+        }
+    }
+
+    #[allow(clippy::vec_init_then_push)]
+    pub fn build(self, bearer_code: &str) -> RequestBuilder {
+        let mut query_parameters = vec![];
+        query_parameters.push(("fields", self.fields.iter().join(",")));
+        let client = reqwest::Client::new()
+            .post(make_open_url(URL, &self.options))
+            .query(&query_parameters)
+            .json(&self.body)
+            .bearer_auth(bearer_code);
+        apply_timeout(client, &self.options)
+    }
+
+    pub async fn execute(self, bearer_code: &str) -> Result<ApiResponse<Response>, ApiError> {
+        self.body.validate()?;
+        execute_api(|| self.clone().build(bearer_code), &self.options).await
+    }
+
+    // cursor と search_id を引き継いで has_more が false になるまで取得する
+    pub async fn execute_all(self, bearer_code: &str) -> Result<Vec<ResearchVideo>, ApiError> {
+        let mut videos = vec![];
+        let mut api = self;
+        loop {
+            let res = api.clone().execute(bearer_code).await?;
+            check_open_api_error(res.body.error, res.status_code)?;
+            let Some(data) = res.body.data else {
+                break;
+            };
+            videos.extend(data.videos.unwrap_or_default());
+            if !data.has_more.unwrap_or(false) || data.cursor.is_none() {
+                break;
+            }
+            api.body.cursor = data.cursor;
+            api.body.search_id = data.search_id.or(api.body.search_id);
+        }
+        Ok(videos)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Response {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Data>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<OpenApiError>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Response {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .data
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .error
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Response {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub videos: Option<Vec<ResearchVideo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Data {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .videos
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("Data {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_research_video_query_body -- --nocapture --test-threads=1
+    #[test]
+    fn test_research_video_query_body() -> anyhow::Result<()> {
+        let query = Query::new()
+            .and(Condition::is_in(FieldName::RegionCode, &["JP", "US"]))
+            .and(Condition::eq(FieldName::HashtagName, "rust"))
+            .not(Condition::eq(FieldName::Keyword, "spam"));
+        let start_date = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let body = Body::new(
+            query.clone(),
+            start_date,
+            start_date + chrono::Days::new(30),
+        )?
+        .max_count(100);
+        let value = serde_json::to_value(&body)?;
+        assert_eq!(value["start_date"], "20260101");
+        assert_eq!(value["end_date"], "20260131");
+        assert_eq!(
+            value["query"]["and"][0],
+            serde_json::json!({"operation": "IN", "field_name": "region_code", "field_values": ["JP", "US"]})
+        );
+        assert_eq!(value["query"]["not"][0]["field_name"], "keyword");
+        assert!(value["query"].get("or").is_none());
+
+        assert!(
+            Body::new(
+                query.clone(),
+                start_date,
+                start_date + chrono::Days::new(31)
+            )
+            .is_err()
+        );
+        assert!(Body::new(query.clone(), start_date, start_date - chrono::Days::new(1)).is_err());
+        assert!(Body::new(Query::new(), start_date, start_date).is_err());
+        assert!(
+            Body::new(query, start_date, start_date)?
+                .max_count(101)
+                .validate()
+                .is_err()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use crate::error::Error;
+use csv_core::ReadRecordResult;
+use reqwest::header::CONTENT_TYPE;
+use serde::Deserialize;
+
+// CSVの代わりに返ってくるエラーのJSON
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+    code: i64,
+    message: String,
+}
+
+// CSVを1行ずつ読み込む。ファイル全体をメモリに載せない。
+pub struct CsvStream {
+    response: reqwest::Response,
+    reader: csv_core::Reader,
+    chunk: Vec<u8>,
+    position: usize,
+    eof: bool,
+    headers: Vec<String>,
+}
+
+impl CsvStream {
+    pub(crate) async fn new(response: reqwest::Response) -> Result<Self, Error> {
+        let is_json = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|it| it.to_str().ok())
+            .is_some_and(|it| it.contains("json"));
+        let mut reader = Self {
+            response,
+            reader: csv_core::Reader::new(),
+            chunk: vec![],
+            position: 0,
+            eof: false,
+            headers: vec![],
+        };
+        reader.fill().await?;
+        // HTTP 200 でもエラーのJSONが返ってくることがある
+        if is_json || reader.starts_with_json() {
+            return Err(reader.into_error().await);
+        }
+        reader.headers = reader.next_record().await?.unwrap_or_default();
+        if let Some(first) = reader.headers.first_mut() {
+            // UTF-8 BOM
+            *first = first.trim_start_matches('\u{feff}').to_owned();
+        }
+        Ok(reader)
+    }
+
+    async fn fill(&mut self) -> Result<(), Error> {
+        while self.position >= self.chunk.len() && !self.eof {
+            match self.response.chunk().await? {
+                Some(chunk) => {
+                    self.chunk = chunk.to_vec();
+                    self.position = 0;
+                }
+                None => self.eof = true,
+            }
+        }
+        Ok(())
+    }
+
+    fn starts_with_json(&self) -> bool {
+        let head = &self.chunk[self.position..];
+        let head = head.strip_prefix(b"\xef\xbb\xbf").unwrap_or(head);
+        head.iter()
+            .find(|it| !it.is_ascii_whitespace())
+            .is_some_and(|it| *it == b'{')
+    }
+
+    async fn into_error(mut self) -> Error {
+        let status_code = self.response.status();
+        let mut body = self.chunk.split_off(self.position);
+        while let Ok(Some(chunk)) = self.response.chunk().await {
+            body.extend_from_slice(&chunk);
+        }
+        let text = String::from_utf8_lossy(&body).into_owned();
+        match serde_json::from_str::<ErrorEnvelope>(text.trim_start_matches('\u{feff}')) {
+            Ok(envelope) => Error::Api(envelope.code, envelope.message, status_code),
+            Err(_) => Error::Other(text, status_code),
+        }
+    }
+
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    pub async fn next_record(&mut self) -> Result<Option<Vec<String>>, Error> {
+        let mut output = vec![0u8; 1024];
+        let mut output_len = 0;
+        let mut ends = vec![0usize; 64];
+        let mut ends_len = 0;
+        loop {
+            self.fill().await?;
+            let input = if self.eof {
+                &[][..]
+            } else {
+                &self.chunk[self.position..]
+            };
+            let (result, nin, nout, nend) =
+                self.reader
+                    .read_record(input, &mut output[output_len..], &mut ends[ends_len..]);
+            self.position += nin;
+            output_len += nout;
+            ends_len += nend;
+            match result {
+                ReadRecordResult::InputEmpty => {}
+                ReadRecordResult::OutputFull => output.resize(output.len() * 2, 0),
+                ReadRecordResult::OutputEndsFull => ends.resize(ends.len() * 2, 0),
+                ReadRecordResult::Record => {
+                    let mut start = 0;
+                    let mut record = vec![];
+                    for end in &ends[..ends_len] {
+                        record.push(String::from_utf8_lossy(&output[start..*end]).into_owned());
+                        start = *end;
+                    }
+                    return Ok(Some(record));
+                }
+                ReadRecordResult::End => return Ok(None),
+            }
+        }
+    }
+}
@@ -13,6 +13,12 @@ pub enum Error {
     #[error("Other {0}, {1}")]
     Other(String, StatusCode),
 
+    #[error("Api {0} {1}, {2}")]
+    Api(i64, String, StatusCode),
+
+    #[error("OpenApi {0} {1}, {2}")]
+    OpenApi(String, String, StatusCode),
+
     #[error("OAuth {0:?}, {1}")]
     OAuth(OAuthError, StatusCode),
 
@@ -21,6 +27,9 @@ pub enum Error {
 
     #[error("serde json {0}")]
     Json(#[from] serde_json::Error),
+
+    #[error("io {0}")]
+    Io(#[from] std::io::Error),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
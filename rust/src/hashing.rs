@@ -0,0 +1,54 @@
+use crate::error::Error;
+use md5::Md5;
+use sha2::{Digest, Sha256};
+
+pub fn hash_email(value: &str) -> String {
+    sha256_hex(&value.trim().to_lowercase())
+}
+
+// 国番号付きのE.164形式のみ受け付ける。090-1234-5678 のような国内形式はエラー
+pub fn hash_phone(value: &str) -> Result<String, Error> {
+    let Some(number) = value.trim().strip_prefix('+') else {
+        return Err(Error::Invalid("phone must start with +".to_owned()));
+    };
+    let digits: String = number
+        .chars()
+        .filter(|it| !matches!(it, ' ' | '-' | '(' | ')' | '.'))
+        .collect();
+    if !(8..=15).contains(&digits.len())
+        || !digits.chars().all(|it| it.is_ascii_digit())
+        || digits.starts_with('0')
+    {
+        return Err(Error::Invalid("phone is not E.164".to_owned()));
+    }
+    Ok(sha256_hex(&format!("+{}", digits)))
+}
+
+pub fn hash_idfa(value: &str) -> String {
+    sha256_hex(&value.trim().to_uppercase())
+}
+
+pub fn hash_gaid(value: &str) -> String {
+    sha256_hex(&value.trim().to_lowercase())
+}
+
+// MD5 の場合も正規化は SHA256 と同じ
+pub fn hash_idfa_md5(value: &str) -> String {
+    md5_hex(&value.trim().to_uppercase())
+}
+
+pub fn hash_gaid_md5(value: &str) -> String {
+    md5_hex(&value.trim().to_lowercase())
+}
+
+pub fn hash_external_id(value: &str) -> String {
+    sha256_hex(value.trim())
+}
+
+pub(crate) fn sha256_hex(value: &str) -> String {
+    hex::encode(Sha256::digest(value.as_bytes()))
+}
+
+fn md5_hex(value: &str) -> String {
+    hex::encode(Md5::digest(value.as_bytes()))
+}
@@ -1,8 +1,14 @@
 pub mod apis;
+pub mod csv_stream;
 pub mod error;
 pub mod oauth;
+pub mod oembed_cache;
 pub mod options;
 pub mod responses;
+pub mod tool_cache;
+pub mod upload;
 
+#[cfg(any(feature = "events", feature = "audience"))]
+pub mod hashing;
 #[cfg(feature = "webhook")]
 pub mod webhook;
@@ -1,6 +1,6 @@
 use crate::{
-    error::Error,
-    options::{TiktokOptions, apply_timeout, make_url},
+    error::{Error, OAuthError},
+    options::{TiktokOptions, apply_timeout, make_open_url, make_url},
 };
 use base64::prelude::{BASE64_URL_SAFE_NO_PAD, Engine as _};
 use itertools::Itertools;
@@ -13,13 +13,17 @@ const TOKEN_URL: &str = "/tt_user/oauth2/token/";
 const REFRESH_TOKEN_URL: &str = "/tt_user/oauth2/refresh_token/";
 const REVOKE_URL: &str = "/tt_user/oauth2/revoke/";
 const TOKEN_INFO_URL: &str = "/tt_user/token_info/get/";
+const OPEN_TOKEN_URL: &str = "/v2/oauth/token/";
+const OPEN_REVOKE_URL: &str = "/v2/oauth/revoke/";
 
 pub mod scope;
 pub use scope::TiktokScope;
+pub mod client_credentials;
+pub use client_credentials::TiktokClientCredentials;
 use serde::de::DeserializeOwned;
 use serde_json::json;
 
-use self::response::{RevokeResponse, TokenInfoResponse, TokenResponse};
+use self::response::{OpenTokenData, RevokeResponse, TokenInfoResponse, TokenResponse};
 pub mod response;
 
 #[derive(Debug, Clone)]
@@ -116,6 +120,45 @@ impl TiktokOauth {
         });
         make_response(TOKEN_INFO_URL, &json, &self.options).await
     }
+
+    // Login Kit (open.tiktokapis.com/v2) の個人アカウント用トークン
+    pub async fn open_token(&self, code: &str) -> Result<OpenTokenData, Error> {
+        let form = [
+            ("client_key", self.client_key.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("code", code),
+            ("grant_type", "authorization_code"),
+            ("redirect_uri", self.callback_url.as_str()),
+        ];
+        make_open_response(OPEN_TOKEN_URL, &form, &self.options).await
+    }
+
+    pub async fn open_refresh(&self, refresh_token: &str) -> Result<OpenTokenData, Error> {
+        let form = [
+            ("client_key", self.client_key.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ];
+        make_open_response(OPEN_TOKEN_URL, &form, &self.options).await
+    }
+
+    // 成功時は空のレスポンスが返ってくる
+    pub async fn open_revoke(&self, access_token: &str) -> Result<(), Error> {
+        let form = [
+            ("client_key", self.client_key.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("token", access_token),
+        ];
+        let response = execute_open_send(OPEN_REVOKE_URL, &form, &self.options).await?;
+        let status_code = response.status();
+        let text = response.text().await?;
+        if text.trim().is_empty() {
+            return Ok(());
+        }
+        let value: serde_json::Value = serde_json::from_str(&text)?;
+        parse_open_response::<serde_json::Value>(value, status_code).map(|_| ())
+    }
 }
 
 async fn execute_send(
@@ -130,6 +173,18 @@ async fn execute_send(
     apply_timeout(builder, options).send().await
 }
 
+async fn execute_open_send(
+    url: &str,
+    form: &[(&str, &str)],
+    options: &Option<TiktokOptions>,
+) -> Result<reqwest::Response, reqwest::Error> {
+    let builder = reqwest::Client::new()
+        .post(make_open_url(url, options))
+        .header(CACHE_CONTROL, "no-cache")
+        .form(form);
+    apply_timeout(builder, options).send().await
+}
+
 fn csrf_token() -> String {
     let random_bytes: Vec<u8> = (0..16).map(|_| rand::rng().random::<u8>()).collect();
     BASE64_URL_SAFE_NO_PAD.encode(random_bytes)
@@ -148,3 +203,32 @@ where
     let res: T = response.json().await?;
     Ok((res, status_code))
 }
+
+async fn make_open_response<T>(
+    url: &str,
+    form: &[(&str, &str)],
+    options: &Option<TiktokOptions>,
+) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let response = execute_open_send(url, form, options).await?;
+    let status_code = response.status();
+    let value: serde_json::Value = response.json().await?;
+    parse_open_response(value, status_code)
+}
+
+// 失敗時は error / error_description / log_id が返ってくる
+fn parse_open_response<T>(value: serde_json::Value, status_code: StatusCode) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    if value
+        .get("error")
+        .is_some_and(|it| !it.is_null() && it.as_str() != Some(""))
+    {
+        let error: OAuthError = serde_json::from_value(value)?;
+        return Err(Error::OAuth(error, status_code));
+    }
+    Ok(serde_json::from_value(value)?)
+}
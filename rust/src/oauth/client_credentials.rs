@@ -0,0 +1,62 @@
+use crate::{error::Error, options::TiktokOptions};
+
+use super::{OPEN_TOKEN_URL, make_open_response, response::ClientTokenData};
+
+// Research API などで使うアプリ単位のトークン
+pub struct TiktokClientCredentials {
+    client_key: String,
+    client_secret: String,
+    options: Option<TiktokOptions>,
+}
+
+impl TiktokClientCredentials {
+    pub fn new(client_key: &str, client_secret: &str) -> Self {
+        Self::new_with_options(client_key, client_secret, None)
+    }
+
+    pub fn new_with_options(
+        client_key: &str,
+        client_secret: &str,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            client_key: client_key.to_owned(),
+            client_secret: client_secret.to_owned(),
+            options,
+        }
+    }
+
+    pub async fn token(&self) -> Result<ClientTokenData, Error> {
+        let form = [
+            ("client_key", self.client_key.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("grant_type", "client_credentials"),
+        ];
+        make_open_response(OPEN_TOKEN_URL, &form, &self.options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oauth::parse_open_response;
+
+    // cargo test test_client_credentials_parse_token -- --nocapture --test-threads=1
+    #[test]
+    fn test_client_credentials_parse_token() -> anyhow::Result<()> {
+        let data: ClientTokenData = parse_open_response(
+            serde_json::json!({"access_token": "clt.abc", "expires_in": 7200, "token_type": "Bearer"}),
+            reqwest::StatusCode::OK,
+        )?;
+        assert_eq!(data.access_token, "clt.abc");
+        assert_eq!(data.expires_in, 7200);
+
+        let err = parse_open_response::<ClientTokenData>(
+            serde_json::json!({"error": "invalid_client", "error_description": "Client key or secret is incorrect.", "log_id": "123"}),
+            reqwest::StatusCode::UNAUTHORIZED,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::OAuth(error, _) if error.error == "invalid_client"));
+        Ok(())
+    }
+}
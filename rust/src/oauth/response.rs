@@ -29,3 +29,21 @@ pub struct TokenInfoData {
     pub scope: String,
     pub creator_id: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientTokenData {
+    pub access_token: String,
+    pub expires_in: u64,
+    pub token_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenTokenData {
+    pub open_id: String,
+    pub scope: String,
+    pub access_token: String,
+    pub expires_in: u64,
+    pub refresh_token: String,
+    pub refresh_expires_in: u64,
+    pub token_type: String,
+}
@@ -1,3 +1,4 @@
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum TiktokScope {
     CommentList,
     CommentListManage,
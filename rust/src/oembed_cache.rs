@@ -0,0 +1,133 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    apis::get_oembed,
+    error::Error,
+    options::TiktokOptions,
+    responses::{oembed::OEmbed, video::Video},
+};
+
+// oEmbedの結果を動画のURLごとにttlの間キャッシュする
+#[derive(Debug, Default)]
+pub struct OEmbedCache {
+    options: Option<TiktokOptions>,
+    ttl: Duration,
+    values: Mutex<HashMap<String, (Instant, OEmbed)>>,
+}
+
+impl OEmbedCache {
+    pub fn new(options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            ttl: Duration::from_secs(60 * 60),
+            ..Default::default()
+        }
+    }
+
+    pub fn ttl(mut self, value: Duration) -> Self {
+        self.ttl = value;
+        self
+    }
+
+    pub fn clear(&self) {
+        self.values.lock().unwrap().clear();
+    }
+
+    pub async fn oembed(&self, url: &str) -> Result<OEmbed, Error> {
+        if let Some(value) = self.get(url) {
+            return Ok(value);
+        }
+        let res = get_oembed::Api::new(url, self.options.clone())
+            .execute()
+            .await?;
+        if res.body.html.is_none() {
+            return Err(Error::Other(
+                serde_json::to_string(&res.body.extra)?,
+                res.status_code,
+            ));
+        }
+        self.set(url, res.body.clone());
+        Ok(res.body)
+    }
+
+    // share_url から埋め込み用の html を取得する
+    pub async fn embed_html(&self, video: &Video) -> Result<Option<String>, Error> {
+        let Some(url) = video.share_url.as_deref() else {
+            return Ok(None);
+        };
+        Ok(self.oembed(url).await?.html)
+    }
+
+    // 期限切れのエントリは取り出す時に削除する
+    fn get(&self, url: &str) -> Option<OEmbed> {
+        let mut values = self.values.lock().unwrap();
+        let (fetched_at, value) = values.get(url)?;
+        if fetched_at.elapsed() > self.ttl {
+            values.remove(url);
+            return None;
+        }
+        Some(value.clone())
+    }
+
+    // 一度しか参照されないURLが残り続けないように、追加時に期限切れを掃除する
+    fn set(&self, url: &str, value: OEmbed) {
+        let mut values = self.values.lock().unwrap();
+        values.retain(|_, (fetched_at, _)| fetched_at.elapsed() <= self.ttl);
+        values.insert(url.to_owned(), (Instant::now(), value));
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_oembed_cache_embed_html -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_oembed_cache_embed_html() -> anyhow::Result<()> {
+        let cache = OEmbedCache::new(None);
+        let url = "https://www.tiktok.com/@scout2015/video/6718335390845095173";
+        let oembed: OEmbed = serde_json::from_str(
+            r#"{"version":"1.0","type":"video","title":"Scramble up ur name","author_url":"https://www.tiktok.com/@scout2015","author_name":"Scout","width":"100%","height":"100%","html":"<blockquote class=\"tiktok-embed\"></blockquote>","thumbnail_width":720,"thumbnail_height":1280,"thumbnail_url":"https://p16.tiktokcdn.com/thumb.jpeg","provider_url":"https://www.tiktok.com","provider_name":"TikTok"}"#,
+        )?;
+        assert!(oembed.is_empty_extra());
+        cache.set(url, oembed);
+
+        let video = Video {
+            share_url: Some(url.to_owned()),
+            ..Default::default()
+        };
+        let html = cache.embed_html(&video).await?;
+        assert_eq!(
+            html.as_deref(),
+            Some("<blockquote class=\"tiktok-embed\"></blockquote>")
+        );
+        assert_eq!(cache.embed_html(&Video::default()).await?, None);
+        Ok(())
+    }
+
+    // cargo test test_oembed_cache_expired -- --nocapture --test-threads=1
+    #[test]
+    fn test_oembed_cache_expired() {
+        let cache = OEmbedCache::new(None).ttl(Duration::ZERO);
+        cache.set("https://www.tiktok.com/@a/video/1", OEmbed::default());
+        std::thread::sleep(Duration::from_millis(5));
+        cache.set("https://www.tiktok.com/@a/video/2", OEmbed::default());
+        assert_eq!(cache.len(), 1);
+
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(cache.get("https://www.tiktok.com/@a/video/2").is_none());
+        assert!(cache.is_empty());
+    }
+}
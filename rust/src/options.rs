@@ -4,13 +4,20 @@ use reqwest::RequestBuilder;
 
 const URL_PREFIX: &str = "https://business-api.tiktok.com/open_api/v1.3";
 const ENV_KEY: &str = "TICTOK_BUSINESS_PREFIX_API";
+const OPEN_URL_PREFIX: &str = "https://open.tiktokapis.com";
+const OPEN_ENV_KEY: &str = "TICTOK_OPEN_PREFIX_API";
+const WWW_URL_PREFIX: &str = "https://www.tiktok.com";
+const WWW_ENV_KEY: &str = "TICTOK_WWW_PREFIX_API";
 
 #[derive(Debug, Clone, Default)]
 pub struct TiktokOptions {
     pub prefix_url: Option<String>,
+    pub open_prefix_url: Option<String>,
+    pub www_prefix_url: Option<String>,
     pub timeout_duration: Option<Duration>,
     pub try_count: Option<u8>,
     pub retry_duration: Option<Duration>,
+    pub poll_timeout_duration: Option<Duration>,
 }
 
 pub fn clear_prefix_url() {
@@ -23,6 +30,26 @@ pub fn setup_prefix_url(url: &str) {
     unsafe { std::env::set_var(ENV_KEY, url) };
 }
 
+pub fn clear_open_prefix_url() {
+    // TODO: Audit that the environment access only happens in single-threaded code.
+    unsafe { std::env::set_var(OPEN_ENV_KEY, OPEN_URL_PREFIX) };
+}
+
+pub fn setup_open_prefix_url(url: &str) {
+    // TODO: Audit that the environment access only happens in single-threaded code.
+    unsafe { std::env::set_var(OPEN_ENV_KEY, url) };
+}
+
+pub fn clear_www_prefix_url() {
+    // TODO: Audit that the environment access only happens in single-threaded code.
+    unsafe { std::env::set_var(WWW_ENV_KEY, WWW_URL_PREFIX) };
+}
+
+pub fn setup_www_prefix_url(url: &str) {
+    // TODO: Audit that the environment access only happens in single-threaded code.
+    unsafe { std::env::set_var(WWW_ENV_KEY, url) };
+}
+
 pub(crate) fn make_url(postfix_url: &str, options: &Option<TiktokOptions>) -> String {
     make_url_with_prefix(
         &std::env::var(ENV_KEY).unwrap_or(URL_PREFIX.to_owned()),
@@ -31,6 +58,24 @@ pub(crate) fn make_url(postfix_url: &str, options: &Option<TiktokOptions>) -> St
     )
 }
 
+// open.tiktokapis.com のAPI用
+pub(crate) fn make_open_url(postfix_url: &str, options: &Option<TiktokOptions>) -> String {
+    let prefix_url = options
+        .as_ref()
+        .and_then(|it| it.open_prefix_url.clone())
+        .unwrap_or(std::env::var(OPEN_ENV_KEY).unwrap_or(OPEN_URL_PREFIX.to_owned()));
+    format!("{}{}", prefix_url, postfix_url)
+}
+
+// www.tiktok.com のAPI用
+pub(crate) fn make_www_url(postfix_url: &str, options: &Option<TiktokOptions>) -> String {
+    let prefix_url = options
+        .as_ref()
+        .and_then(|it| it.www_prefix_url.clone())
+        .unwrap_or(std::env::var(WWW_ENV_KEY).unwrap_or(WWW_URL_PREFIX.to_owned()));
+    format!("{}{}", prefix_url, postfix_url)
+}
+
 fn make_url_with_prefix(
     default_perfix_url: &str,
     options: &Option<TiktokOptions>,
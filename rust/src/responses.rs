@@ -1,10 +1,58 @@
 pub mod account;
+pub mod ad;
+pub mod ad_group;
+pub mod ad_image;
+pub mod ad_video;
+pub mod adlib_ad;
+pub mod adlib_advertiser;
+pub mod advertiser_balance;
+pub mod advertiser_info;
 pub mod audience_activity;
 pub mod audience_country;
 pub mod audience_gender;
+pub mod bc_asset;
+pub mod bc_member;
+pub mod benchmark;
+pub mod blocked_word;
+pub mod business_center;
+pub mod campaign;
+pub mod catalog;
+pub mod catalog_feed;
+pub mod catalog_product;
 pub mod comment;
+pub mod commercial_content;
 pub mod create_reply;
+pub mod custom_audience;
+pub mod device_model;
+pub mod identity;
+pub mod identity_video;
 pub mod impression_source;
+pub mod interest_category;
+pub mod language;
+pub mod lead;
+pub mod lead_form;
+pub mod mentioned_comment;
+pub mod mentioned_video;
 pub mod metric;
+pub mod oembed;
+pub mod open_api_error;
+pub mod open_user;
+pub mod open_video;
+pub mod optimizer_rule;
+pub mod optimizer_rule_result;
+pub mod os_version;
+pub mod page_info;
+pub mod region;
 pub mod reply;
+pub mod report_dimensions;
+pub mod report_metrics;
+pub mod report_row;
+pub mod research_comment;
+pub mod research_follower;
+pub mod research_user;
+pub mod research_video;
+pub mod spark_ad_post;
+pub(crate) mod string_number;
 pub mod video;
+#[cfg(feature = "webhook")]
+pub mod webhook_subscription;
@@ -0,0 +1,280 @@
+use crate::responses::campaign::OperationStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Ad {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adgroup_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_format: Option<AdFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_type: Option<IdentityType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_authorized_bc_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action: Option<CallToAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landing_page_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiktok_item_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_pixel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_status: Option<OperationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modify_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Ad {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Ad {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum AdFormat {
+    #[serde(rename = "SINGLE_VIDEO")]
+    #[default]
+    SingleVideo,
+    #[serde(rename = "SINGLE_IMAGE")]
+    SingleImage,
+    #[serde(rename = "CAROUSEL_ADS")]
+    CarouselAds,
+    #[serde(rename = "CATALOG_CAROUSEL")]
+    CatalogCarousel,
+    #[serde(rename = "LIVE_CONTENT")]
+    LiveContent,
+}
+
+impl std::fmt::Display for AdFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::SingleVideo => write!(f, "SINGLE_VIDEO"),
+            Self::SingleImage => write!(f, "SINGLE_IMAGE"),
+            Self::CarouselAds => write!(f, "CAROUSEL_ADS"),
+            Self::CatalogCarousel => write!(f, "CATALOG_CAROUSEL"),
+            Self::LiveContent => write!(f, "LIVE_CONTENT"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum IdentityType {
+    #[serde(rename = "CUSTOMIZED_USER")]
+    #[default]
+    CustomizedUser,
+    #[serde(rename = "AUTH_CODE")]
+    AuthCode,
+    #[serde(rename = "TT_USER")]
+    TtUser,
+    #[serde(rename = "BC_AUTH_TT")]
+    BcAuthTt,
+}
+
+impl std::fmt::Display for IdentityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::CustomizedUser => write!(f, "CUSTOMIZED_USER"),
+            Self::AuthCode => write!(f, "AUTH_CODE"),
+            Self::TtUser => write!(f, "TT_USER"),
+            Self::BcAuthTt => write!(f, "BC_AUTH_TT"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum CallToAction {
+    #[serde(rename = "LEARN_MORE")]
+    #[default]
+    LearnMore,
+    #[serde(rename = "DOWNLOAD_NOW")]
+    DownloadNow,
+    #[serde(rename = "SHOP_NOW")]
+    ShopNow,
+    #[serde(rename = "SIGN_UP")]
+    SignUp,
+    #[serde(rename = "CONTACT_US")]
+    ContactUs,
+    #[serde(rename = "APPLY_NOW")]
+    ApplyNow,
+    #[serde(rename = "BOOK_NOW")]
+    BookNow,
+    #[serde(rename = "PLAY_GAME")]
+    PlayGame,
+    #[serde(rename = "WATCH_NOW")]
+    WatchNow,
+    #[serde(rename = "READ_MORE")]
+    ReadMore,
+    #[serde(rename = "VIEW_NOW")]
+    ViewNow,
+    #[serde(rename = "GET_QUOTE")]
+    GetQuote,
+    #[serde(rename = "ORDER_NOW")]
+    OrderNow,
+    #[serde(rename = "INSTALL_NOW")]
+    InstallNow,
+    #[serde(rename = "GET_SHOWTIMES")]
+    GetShowtimes,
+    #[serde(rename = "LISTEN_NOW")]
+    ListenNow,
+    #[serde(rename = "INTERESTED")]
+    Interested,
+    #[serde(rename = "SUBSCRIBE")]
+    Subscribe,
+    #[serde(rename = "GET_TICKETS_NOW")]
+    GetTicketsNow,
+    #[serde(rename = "EXPERIENCE_NOW")]
+    ExperienceNow,
+    #[serde(rename = "PREORDER_NOW")]
+    PreorderNow,
+    #[serde(rename = "VISIT_STORE")]
+    VisitStore,
+    #[serde(rename = "CALL_NOW")]
+    CallNow,
+    #[serde(rename = "SEND_MESSAGE")]
+    SendMessage,
+    #[serde(rename = "VIEW_PROFILE")]
+    ViewProfile,
+}
+
+impl std::fmt::Display for CallToAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::LearnMore => write!(f, "LEARN_MORE"),
+            Self::DownloadNow => write!(f, "DOWNLOAD_NOW"),
+            Self::ShopNow => write!(f, "SHOP_NOW"),
+            Self::SignUp => write!(f, "SIGN_UP"),
+            Self::ContactUs => write!(f, "CONTACT_US"),
+            Self::ApplyNow => write!(f, "APPLY_NOW"),
+            Self::BookNow => write!(f, "BOOK_NOW"),
+            Self::PlayGame => write!(f, "PLAY_GAME"),
+            Self::WatchNow => write!(f, "WATCH_NOW"),
+            Self::ReadMore => write!(f, "READ_MORE"),
+            Self::ViewNow => write!(f, "VIEW_NOW"),
+            Self::GetQuote => write!(f, "GET_QUOTE"),
+            Self::OrderNow => write!(f, "ORDER_NOW"),
+            Self::InstallNow => write!(f, "INSTALL_NOW"),
+            Self::GetShowtimes => write!(f, "GET_SHOWTIMES"),
+            Self::ListenNow => write!(f, "LISTEN_NOW"),
+            Self::Interested => write!(f, "INTERESTED"),
+            Self::Subscribe => write!(f, "SUBSCRIBE"),
+            Self::GetTicketsNow => write!(f, "GET_TICKETS_NOW"),
+            Self::ExperienceNow => write!(f, "EXPERIENCE_NOW"),
+            Self::PreorderNow => write!(f, "PREORDER_NOW"),
+            Self::VisitStore => write!(f, "VISIT_STORE"),
+            Self::CallNow => write!(f, "CALL_NOW"),
+            Self::SendMessage => write!(f, "SEND_MESSAGE"),
+            Self::ViewProfile => write!(f, "VIEW_PROFILE"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AdField {
+    AdvertiserId,
+    CampaignId,
+    AdgroupId,
+    AdId,
+    AdName,
+    AdFormat,
+    IdentityType,
+    IdentityId,
+    IdentityAuthorizedBcId,
+    VideoId,
+    ImageIds,
+    AdText,
+    CallToAction,
+    LandingPageUrl,
+    DisplayName,
+    TiktokItemId,
+    TrackingPixelId,
+    OperationStatus,
+    SecondaryStatus,
+    CreateTime,
+    ModifyTime,
+}
+
+impl AdField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(AdField::AdvertiserId);
+        set.insert(AdField::CampaignId);
+        set.insert(AdField::AdgroupId);
+        set.insert(AdField::AdId);
+        set.insert(AdField::AdName);
+        set.insert(AdField::AdFormat);
+        set.insert(AdField::IdentityType);
+        set.insert(AdField::IdentityId);
+        set.insert(AdField::IdentityAuthorizedBcId);
+        set.insert(AdField::VideoId);
+        set.insert(AdField::ImageIds);
+        set.insert(AdField::AdText);
+        set.insert(AdField::CallToAction);
+        set.insert(AdField::LandingPageUrl);
+        set.insert(AdField::DisplayName);
+        set.insert(AdField::TiktokItemId);
+        set.insert(AdField::TrackingPixelId);
+        set.insert(AdField::OperationStatus);
+        set.insert(AdField::SecondaryStatus);
+        set.insert(AdField::CreateTime);
+        set.insert(AdField::ModifyTime);
+        set
+    }
+}
+
+impl std::fmt::Display for AdField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AdvertiserId => write!(f, "advertiser_id"),
+            Self::CampaignId => write!(f, "campaign_id"),
+            Self::AdgroupId => write!(f, "adgroup_id"),
+            Self::AdId => write!(f, "ad_id"),
+            Self::AdName => write!(f, "ad_name"),
+            Self::AdFormat => write!(f, "ad_format"),
+            Self::IdentityType => write!(f, "identity_type"),
+            Self::IdentityId => write!(f, "identity_id"),
+            Self::IdentityAuthorizedBcId => write!(f, "identity_authorized_bc_id"),
+            Self::VideoId => write!(f, "video_id"),
+            Self::ImageIds => write!(f, "image_ids"),
+            Self::AdText => write!(f, "ad_text"),
+            Self::CallToAction => write!(f, "call_to_action"),
+            Self::LandingPageUrl => write!(f, "landing_page_url"),
+            Self::DisplayName => write!(f, "display_name"),
+            Self::TiktokItemId => write!(f, "tiktok_item_id"),
+            Self::TrackingPixelId => write!(f, "tracking_pixel_id"),
+            Self::OperationStatus => write!(f, "operation_status"),
+            Self::SecondaryStatus => write!(f, "secondary_status"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::ModifyTime => write!(f, "modify_time"),
+        }
+    }
+}
@@ -0,0 +1,430 @@
+use crate::responses::campaign::BudgetMode;
+use crate::responses::campaign::OperationStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdGroup {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adgroup_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adgroup_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement_type: Option<PlacementType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placements: Option<Vec<Placement>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_groups: Option<Vec<AgeGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub languages: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interest_category_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interest_keyword_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operating_systems: Option<Vec<OperatingSystem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_mode: Option<BudgetMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_type: Option<ScheduleType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dayparting: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimization_goal: Option<OptimizationGoal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimization_event: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pixel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_type: Option<BidType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversion_bid_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_event: Option<BillingEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pacing: Option<Pacing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_status: Option<OperationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modify_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdGroup {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("AdGroup {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum PlacementType {
+    #[serde(rename = "PLACEMENT_TYPE_AUTOMATIC")]
+    #[default]
+    PlacementTypeAutomatic,
+    #[serde(rename = "PLACEMENT_TYPE_NORMAL")]
+    PlacementTypeNormal,
+}
+
+impl std::fmt::Display for PlacementType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PlacementTypeAutomatic => write!(f, "PLACEMENT_TYPE_AUTOMATIC"),
+            Self::PlacementTypeNormal => write!(f, "PLACEMENT_TYPE_NORMAL"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Placement {
+    #[serde(rename = "PLACEMENT_TIKTOK")]
+    #[default]
+    PlacementTiktok,
+    #[serde(rename = "PLACEMENT_PANGLE")]
+    PlacementPangle,
+    #[serde(rename = "PLACEMENT_GLOBAL_APP_BUNDLE")]
+    PlacementGlobalAppBundle,
+}
+
+impl std::fmt::Display for Placement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PlacementTiktok => write!(f, "PLACEMENT_TIKTOK"),
+            Self::PlacementPangle => write!(f, "PLACEMENT_PANGLE"),
+            Self::PlacementGlobalAppBundle => write!(f, "PLACEMENT_GLOBAL_APP_BUNDLE"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum AgeGroup {
+    #[serde(rename = "AGE_13_17")]
+    #[default]
+    Age1317,
+    #[serde(rename = "AGE_18_24")]
+    Age1824,
+    #[serde(rename = "AGE_25_34")]
+    Age2534,
+    #[serde(rename = "AGE_35_44")]
+    Age3544,
+    #[serde(rename = "AGE_45_54")]
+    Age4554,
+    #[serde(rename = "AGE_55_100")]
+    Age55100,
+}
+
+impl std::fmt::Display for AgeGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Age1317 => write!(f, "AGE_13_17"),
+            Self::Age1824 => write!(f, "AGE_18_24"),
+            Self::Age2534 => write!(f, "AGE_25_34"),
+            Self::Age3544 => write!(f, "AGE_35_44"),
+            Self::Age4554 => write!(f, "AGE_45_54"),
+            Self::Age55100 => write!(f, "AGE_55_100"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Gender {
+    #[serde(rename = "GENDER_UNLIMITED")]
+    #[default]
+    GenderUnlimited,
+    #[serde(rename = "GENDER_MALE")]
+    GenderMale,
+    #[serde(rename = "GENDER_FEMALE")]
+    GenderFemale,
+}
+
+impl std::fmt::Display for Gender {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::GenderUnlimited => write!(f, "GENDER_UNLIMITED"),
+            Self::GenderMale => write!(f, "GENDER_MALE"),
+            Self::GenderFemale => write!(f, "GENDER_FEMALE"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum OperatingSystem {
+    #[serde(rename = "ANDROID")]
+    #[default]
+    Android,
+    #[serde(rename = "IOS")]
+    Ios,
+}
+
+impl std::fmt::Display for OperatingSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Android => write!(f, "ANDROID"),
+            Self::Ios => write!(f, "IOS"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ScheduleType {
+    #[serde(rename = "SCHEDULE_START_END")]
+    #[default]
+    ScheduleStartEnd,
+    #[serde(rename = "SCHEDULE_FROM_NOW")]
+    ScheduleFromNow,
+}
+
+impl std::fmt::Display for ScheduleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ScheduleStartEnd => write!(f, "SCHEDULE_START_END"),
+            Self::ScheduleFromNow => write!(f, "SCHEDULE_FROM_NOW"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum OptimizationGoal {
+    #[serde(rename = "CLICK")]
+    #[default]
+    Click,
+    #[serde(rename = "CONVERT")]
+    Convert,
+    #[serde(rename = "INSTALL")]
+    Install,
+    #[serde(rename = "IN_APP_EVENT")]
+    InAppEvent,
+    #[serde(rename = "REACH")]
+    Reach,
+    #[serde(rename = "SHOW")]
+    Show,
+    #[serde(rename = "VIDEO_VIEW")]
+    VideoView,
+    #[serde(rename = "ENGAGED_VIEW")]
+    EngagedView,
+    #[serde(rename = "LEAD_GENERATION")]
+    LeadGeneration,
+    #[serde(rename = "FOLLOWERS")]
+    Followers,
+    #[serde(rename = "PROFILE_VIEWS")]
+    ProfileViews,
+    #[serde(rename = "VALUE")]
+    Value,
+    #[serde(rename = "TRAFFIC_LANDING_PAGE_VIEW")]
+    TrafficLandingPageView,
+}
+
+impl std::fmt::Display for OptimizationGoal {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Click => write!(f, "CLICK"),
+            Self::Convert => write!(f, "CONVERT"),
+            Self::Install => write!(f, "INSTALL"),
+            Self::InAppEvent => write!(f, "IN_APP_EVENT"),
+            Self::Reach => write!(f, "REACH"),
+            Self::Show => write!(f, "SHOW"),
+            Self::VideoView => write!(f, "VIDEO_VIEW"),
+            Self::EngagedView => write!(f, "ENGAGED_VIEW"),
+            Self::LeadGeneration => write!(f, "LEAD_GENERATION"),
+            Self::Followers => write!(f, "FOLLOWERS"),
+            Self::ProfileViews => write!(f, "PROFILE_VIEWS"),
+            Self::Value => write!(f, "VALUE"),
+            Self::TrafficLandingPageView => write!(f, "TRAFFIC_LANDING_PAGE_VIEW"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum BidType {
+    #[serde(rename = "BID_TYPE_NO_BID")]
+    #[default]
+    BidTypeNoBid,
+    #[serde(rename = "BID_TYPE_CUSTOM")]
+    BidTypeCustom,
+}
+
+impl std::fmt::Display for BidType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::BidTypeNoBid => write!(f, "BID_TYPE_NO_BID"),
+            Self::BidTypeCustom => write!(f, "BID_TYPE_CUSTOM"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum BillingEvent {
+    #[serde(rename = "CPC")]
+    #[default]
+    Cpc,
+    #[serde(rename = "CPM")]
+    Cpm,
+    #[serde(rename = "OCPM")]
+    Ocpm,
+    #[serde(rename = "CPV")]
+    Cpv,
+}
+
+impl std::fmt::Display for BillingEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Cpc => write!(f, "CPC"),
+            Self::Cpm => write!(f, "CPM"),
+            Self::Ocpm => write!(f, "OCPM"),
+            Self::Cpv => write!(f, "CPV"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Pacing {
+    #[serde(rename = "PACING_MODE_SMOOTH")]
+    #[default]
+    PacingModeSmooth,
+    #[serde(rename = "PACING_MODE_FAST")]
+    PacingModeFast,
+}
+
+impl std::fmt::Display for Pacing {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PacingModeSmooth => write!(f, "PACING_MODE_SMOOTH"),
+            Self::PacingModeFast => write!(f, "PACING_MODE_FAST"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AdGroupField {
+    AdvertiserId,
+    CampaignId,
+    AdgroupId,
+    AdgroupName,
+    PlacementType,
+    Placements,
+    LocationIds,
+    AgeGroups,
+    Gender,
+    Languages,
+    InterestCategoryIds,
+    InterestKeywordIds,
+    OperatingSystems,
+    BudgetMode,
+    Budget,
+    ScheduleType,
+    ScheduleStartTime,
+    ScheduleEndTime,
+    Dayparting,
+    OptimizationGoal,
+    OptimizationEvent,
+    PixelId,
+    BidType,
+    BidPrice,
+    ConversionBidPrice,
+    BillingEvent,
+    Pacing,
+    OperationStatus,
+    SecondaryStatus,
+    CreateTime,
+    ModifyTime,
+}
+
+impl AdGroupField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(AdGroupField::AdvertiserId);
+        set.insert(AdGroupField::CampaignId);
+        set.insert(AdGroupField::AdgroupId);
+        set.insert(AdGroupField::AdgroupName);
+        set.insert(AdGroupField::PlacementType);
+        set.insert(AdGroupField::Placements);
+        set.insert(AdGroupField::LocationIds);
+        set.insert(AdGroupField::AgeGroups);
+        set.insert(AdGroupField::Gender);
+        set.insert(AdGroupField::Languages);
+        set.insert(AdGroupField::InterestCategoryIds);
+        set.insert(AdGroupField::InterestKeywordIds);
+        set.insert(AdGroupField::OperatingSystems);
+        set.insert(AdGroupField::BudgetMode);
+        set.insert(AdGroupField::Budget);
+        set.insert(AdGroupField::ScheduleType);
+        set.insert(AdGroupField::ScheduleStartTime);
+        set.insert(AdGroupField::ScheduleEndTime);
+        set.insert(AdGroupField::Dayparting);
+        set.insert(AdGroupField::OptimizationGoal);
+        set.insert(AdGroupField::OptimizationEvent);
+        set.insert(AdGroupField::PixelId);
+        set.insert(AdGroupField::BidType);
+        set.insert(AdGroupField::BidPrice);
+        set.insert(AdGroupField::ConversionBidPrice);
+        set.insert(AdGroupField::BillingEvent);
+        set.insert(AdGroupField::Pacing);
+        set.insert(AdGroupField::OperationStatus);
+        set.insert(AdGroupField::SecondaryStatus);
+        set.insert(AdGroupField::CreateTime);
+        set.insert(AdGroupField::ModifyTime);
+        set
+    }
+}
+
+impl std::fmt::Display for AdGroupField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AdvertiserId => write!(f, "advertiser_id"),
+            Self::CampaignId => write!(f, "campaign_id"),
+            Self::AdgroupId => write!(f, "adgroup_id"),
+            Self::AdgroupName => write!(f, "adgroup_name"),
+            Self::PlacementType => write!(f, "placement_type"),
+            Self::Placements => write!(f, "placements"),
+            Self::LocationIds => write!(f, "location_ids"),
+            Self::AgeGroups => write!(f, "age_groups"),
+            Self::Gender => write!(f, "gender"),
+            Self::Languages => write!(f, "languages"),
+            Self::InterestCategoryIds => write!(f, "interest_category_ids"),
+            Self::InterestKeywordIds => write!(f, "interest_keyword_ids"),
+            Self::OperatingSystems => write!(f, "operating_systems"),
+            Self::BudgetMode => write!(f, "budget_mode"),
+            Self::Budget => write!(f, "budget"),
+            Self::ScheduleType => write!(f, "schedule_type"),
+            Self::ScheduleStartTime => write!(f, "schedule_start_time"),
+            Self::ScheduleEndTime => write!(f, "schedule_end_time"),
+            Self::Dayparting => write!(f, "dayparting"),
+            Self::OptimizationGoal => write!(f, "optimization_goal"),
+            Self::OptimizationEvent => write!(f, "optimization_event"),
+            Self::PixelId => write!(f, "pixel_id"),
+            Self::BidType => write!(f, "bid_type"),
+            Self::BidPrice => write!(f, "bid_price"),
+            Self::ConversionBidPrice => write!(f, "conversion_bid_price"),
+            Self::BillingEvent => write!(f, "billing_event"),
+            Self::Pacing => write!(f, "pacing"),
+            Self::OperationStatus => write!(f, "operation_status"),
+            Self::SecondaryStatus => write!(f, "secondary_status"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::ModifyTime => write!(f, "modify_time"),
+        }
+    }
+}
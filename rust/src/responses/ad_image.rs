@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdImage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub material_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_carousel_usable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modify_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub displayable: Option<bool>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdImage {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("AdImage {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AdImageField {
+    ImageId,
+    MaterialId,
+    IsCarouselUsable,
+    Width,
+    Height,
+    Format,
+    ImageUrl,
+    Signature,
+    Size,
+    FileName,
+    CreateTime,
+    ModifyTime,
+    Displayable,
+}
+
+impl AdImageField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(AdImageField::ImageId);
+        set.insert(AdImageField::MaterialId);
+        set.insert(AdImageField::IsCarouselUsable);
+        set.insert(AdImageField::Width);
+        set.insert(AdImageField::Height);
+        set.insert(AdImageField::Format);
+        set.insert(AdImageField::ImageUrl);
+        set.insert(AdImageField::Signature);
+        set.insert(AdImageField::Size);
+        set.insert(AdImageField::FileName);
+        set.insert(AdImageField::CreateTime);
+        set.insert(AdImageField::ModifyTime);
+        set.insert(AdImageField::Displayable);
+        set
+    }
+}
+
+impl std::fmt::Display for AdImageField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ImageId => write!(f, "image_id"),
+            Self::MaterialId => write!(f, "material_id"),
+            Self::IsCarouselUsable => write!(f, "is_carousel_usable"),
+            Self::Width => write!(f, "width"),
+            Self::Height => write!(f, "height"),
+            Self::Format => write!(f, "format"),
+            Self::ImageUrl => write!(f, "image_url"),
+            Self::Signature => write!(f, "signature"),
+            Self::Size => write!(f, "size"),
+            Self::FileName => write!(f, "file_name"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::ModifyTime => write!(f, "modify_time"),
+            Self::Displayable => write!(f, "displayable"),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdVideo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_cover_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview_url_expire_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bit_rate: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub material_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_placements: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_download: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modify_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub displayable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_task_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flaw_types: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdVideo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("AdVideo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AdVideoField {
+    VideoId,
+    VideoCoverUrl,
+    Format,
+    PreviewUrl,
+    PreviewUrlExpireTime,
+    Duration,
+    Height,
+    Width,
+    BitRate,
+    Signature,
+    Size,
+    MaterialId,
+    AllowedPlacements,
+    AllowDownload,
+    FileName,
+    CreateTime,
+    ModifyTime,
+    Displayable,
+    FixTaskId,
+    FlawTypes,
+}
+
+impl AdVideoField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(AdVideoField::VideoId);
+        set.insert(AdVideoField::VideoCoverUrl);
+        set.insert(AdVideoField::Format);
+        set.insert(AdVideoField::PreviewUrl);
+        set.insert(AdVideoField::PreviewUrlExpireTime);
+        set.insert(AdVideoField::Duration);
+        set.insert(AdVideoField::Height);
+        set.insert(AdVideoField::Width);
+        set.insert(AdVideoField::BitRate);
+        set.insert(AdVideoField::Signature);
+        set.insert(AdVideoField::Size);
+        set.insert(AdVideoField::MaterialId);
+        set.insert(AdVideoField::AllowedPlacements);
+        set.insert(AdVideoField::AllowDownload);
+        set.insert(AdVideoField::FileName);
+        set.insert(AdVideoField::CreateTime);
+        set.insert(AdVideoField::ModifyTime);
+        set.insert(AdVideoField::Displayable);
+        set.insert(AdVideoField::FixTaskId);
+        set.insert(AdVideoField::FlawTypes);
+        set
+    }
+}
+
+impl std::fmt::Display for AdVideoField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::VideoId => write!(f, "video_id"),
+            Self::VideoCoverUrl => write!(f, "video_cover_url"),
+            Self::Format => write!(f, "format"),
+            Self::PreviewUrl => write!(f, "preview_url"),
+            Self::PreviewUrlExpireTime => write!(f, "preview_url_expire_time"),
+            Self::Duration => write!(f, "duration"),
+            Self::Height => write!(f, "height"),
+            Self::Width => write!(f, "width"),
+            Self::BitRate => write!(f, "bit_rate"),
+            Self::Signature => write!(f, "signature"),
+            Self::Size => write!(f, "size"),
+            Self::MaterialId => write!(f, "material_id"),
+            Self::AllowedPlacements => write!(f, "allowed_placements"),
+            Self::AllowDownload => write!(f, "allow_download"),
+            Self::FileName => write!(f, "file_name"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::ModifyTime => write!(f, "modify_time"),
+            Self::Displayable => write!(f, "displayable"),
+            Self::FixTaskId => write!(f, "fix_task_id"),
+            Self::FlawTypes => write!(f, "flaw_types"),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdlibAd {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_shown_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_shown_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_statement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub videos: Option<Vec<AdlibVideo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reach: Option<Reach>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdlibAd {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .videos
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .reach
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("AdlibAd {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AdlibAdField {
+    Id,
+    FirstShownDate,
+    LastShownDate,
+    Status,
+    StatusStatement,
+    Videos,
+    ImageUrls,
+    Reach,
+}
+
+impl AdlibAdField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(AdlibAdField::Id);
+        set.insert(AdlibAdField::FirstShownDate);
+        set.insert(AdlibAdField::LastShownDate);
+        set.insert(AdlibAdField::Status);
+        set.insert(AdlibAdField::StatusStatement);
+        set.insert(AdlibAdField::Videos);
+        set.insert(AdlibAdField::ImageUrls);
+        set.insert(AdlibAdField::Reach);
+        set
+    }
+}
+
+impl std::fmt::Display for AdlibAdField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Id => write!(f, "id"),
+            Self::FirstShownDate => write!(f, "first_shown_date"),
+            Self::LastShownDate => write!(f, "last_shown_date"),
+            Self::Status => write!(f, "status"),
+            Self::StatusStatement => write!(f, "status_statement"),
+            Self::Videos => write!(f, "videos"),
+            Self::ImageUrls => write!(f, "image_urls"),
+            Self::Reach => write!(f, "reach"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdlibVideo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_image_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdlibVideo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("AdlibVideo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Reach {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_users_seen: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_users_seen_by_country: Option<std::collections::HashMap<String, serde_json::Value>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Reach {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Reach {:?}", self.extra);
+        }
+        res
+    }
+}
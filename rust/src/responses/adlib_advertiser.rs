@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdlibAdvertiser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_for_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdlibAdvertiser {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("AdlibAdvertiser {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AdlibAdvertiserField {
+    BusinessId,
+    BusinessName,
+    PaidForBy,
+    CountryCode,
+}
+
+impl AdlibAdvertiserField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(AdlibAdvertiserField::BusinessId);
+        set.insert(AdlibAdvertiserField::BusinessName);
+        set.insert(AdlibAdvertiserField::PaidForBy);
+        set.insert(AdlibAdvertiserField::CountryCode);
+        set
+    }
+}
+
+impl std::fmt::Display for AdlibAdvertiserField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::BusinessId => write!(f, "business_id"),
+            Self::BusinessName => write!(f, "business_name"),
+            Self::PaidForBy => write!(f, "paid_for_by"),
+            Self::CountryCode => write!(f, "country_code"),
+        }
+    }
+}
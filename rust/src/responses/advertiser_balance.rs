@@ -0,0 +1,162 @@
+use crate::responses::advertiser_info::AdvertiserStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdvertiserBalance {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_status: Option<AdvertiserStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_open_days: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_reminder: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_account_balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frozen_balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cash_balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_cash_balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_grant_balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transferable_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_cost: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_remaining: Option<f64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdvertiserBalance {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("AdvertiserBalance {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AdvertiserBalanceField {
+    AdvertiserId,
+    AdvertiserName,
+    AdvertiserStatus,
+    AdvertiserType,
+    Timezone,
+    Currency,
+    AccountOpenDays,
+    BalanceReminder,
+    Company,
+    ContactName,
+    ContactEmail,
+    CreateTime,
+    AccountBalance,
+    ValidAccountBalance,
+    FrozenBalance,
+    Tax,
+    CashBalance,
+    ValidCashBalance,
+    GrantBalance,
+    ValidGrantBalance,
+    TransferableAmount,
+    BudgetMode,
+    Budget,
+    BudgetCost,
+    BudgetRemaining,
+}
+
+impl AdvertiserBalanceField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(AdvertiserBalanceField::AdvertiserId);
+        set.insert(AdvertiserBalanceField::AdvertiserName);
+        set.insert(AdvertiserBalanceField::AdvertiserStatus);
+        set.insert(AdvertiserBalanceField::AdvertiserType);
+        set.insert(AdvertiserBalanceField::Timezone);
+        set.insert(AdvertiserBalanceField::Currency);
+        set.insert(AdvertiserBalanceField::AccountOpenDays);
+        set.insert(AdvertiserBalanceField::BalanceReminder);
+        set.insert(AdvertiserBalanceField::Company);
+        set.insert(AdvertiserBalanceField::ContactName);
+        set.insert(AdvertiserBalanceField::ContactEmail);
+        set.insert(AdvertiserBalanceField::CreateTime);
+        set.insert(AdvertiserBalanceField::AccountBalance);
+        set.insert(AdvertiserBalanceField::ValidAccountBalance);
+        set.insert(AdvertiserBalanceField::FrozenBalance);
+        set.insert(AdvertiserBalanceField::Tax);
+        set.insert(AdvertiserBalanceField::CashBalance);
+        set.insert(AdvertiserBalanceField::ValidCashBalance);
+        set.insert(AdvertiserBalanceField::GrantBalance);
+        set.insert(AdvertiserBalanceField::ValidGrantBalance);
+        set.insert(AdvertiserBalanceField::TransferableAmount);
+        set.insert(AdvertiserBalanceField::BudgetMode);
+        set.insert(AdvertiserBalanceField::Budget);
+        set.insert(AdvertiserBalanceField::BudgetCost);
+        set.insert(AdvertiserBalanceField::BudgetRemaining);
+        set
+    }
+}
+
+impl std::fmt::Display for AdvertiserBalanceField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AdvertiserId => write!(f, "advertiser_id"),
+            Self::AdvertiserName => write!(f, "advertiser_name"),
+            Self::AdvertiserStatus => write!(f, "advertiser_status"),
+            Self::AdvertiserType => write!(f, "advertiser_type"),
+            Self::Timezone => write!(f, "timezone"),
+            Self::Currency => write!(f, "currency"),
+            Self::AccountOpenDays => write!(f, "account_open_days"),
+            Self::BalanceReminder => write!(f, "balance_reminder"),
+            Self::Company => write!(f, "company"),
+            Self::ContactName => write!(f, "contact_name"),
+            Self::ContactEmail => write!(f, "contact_email"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::AccountBalance => write!(f, "account_balance"),
+            Self::ValidAccountBalance => write!(f, "valid_account_balance"),
+            Self::FrozenBalance => write!(f, "frozen_balance"),
+            Self::Tax => write!(f, "tax"),
+            Self::CashBalance => write!(f, "cash_balance"),
+            Self::ValidCashBalance => write!(f, "valid_cash_balance"),
+            Self::GrantBalance => write!(f, "grant_balance"),
+            Self::ValidGrantBalance => write!(f, "valid_grant_balance"),
+            Self::TransferableAmount => write!(f, "transferable_amount"),
+            Self::BudgetMode => write!(f, "budget_mode"),
+            Self::Budget => write!(f, "budget"),
+            Self::BudgetCost => write!(f, "budget_cost"),
+            Self::BudgetRemaining => write!(f, "budget_remaining"),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AdvertiserInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_bc_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AdvertiserStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejection_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub industry: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_account_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contacter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cellphone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub telephone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_no: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<i64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl AdvertiserInfo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("AdvertiserInfo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum AdvertiserStatus {
+    #[serde(rename = "STATUS_DISABLE")]
+    #[default]
+    StatusDisable,
+    #[serde(rename = "STATUS_PENDING_CONFIRM")]
+    StatusPendingConfirm,
+    #[serde(rename = "STATUS_PENDING_VERIFIED")]
+    StatusPendingVerified,
+    #[serde(rename = "STATUS_CONFIRM_FAIL")]
+    StatusConfirmFail,
+    #[serde(rename = "STATUS_ENABLE")]
+    StatusEnable,
+    #[serde(rename = "STATUS_CONFIRM_FAIL_END")]
+    StatusConfirmFailEnd,
+    #[serde(rename = "STATUS_PENDING_CONFIRM_MODIFY")]
+    StatusPendingConfirmModify,
+    #[serde(rename = "STATUS_CONFIRM_MODIFY_FAIL")]
+    StatusConfirmModifyFail,
+    #[serde(rename = "STATUS_LIMIT")]
+    StatusLimit,
+    #[serde(rename = "STATUS_WAIT_FOR_BPM_AUDIT")]
+    StatusWaitForBpmAudit,
+    #[serde(rename = "STATUS_WAIT_FOR_PUBLIC_AUTH")]
+    StatusWaitForPublicAuth,
+    #[serde(rename = "STATUS_SELF_SERVICE_UNAUDITED")]
+    StatusSelfServiceUnaudited,
+    #[serde(rename = "STATUS_CONTRACT_PENDING")]
+    StatusContractPending,
+}
+
+impl std::fmt::Display for AdvertiserStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::StatusDisable => write!(f, "STATUS_DISABLE"),
+            Self::StatusPendingConfirm => write!(f, "STATUS_PENDING_CONFIRM"),
+            Self::StatusPendingVerified => write!(f, "STATUS_PENDING_VERIFIED"),
+            Self::StatusConfirmFail => write!(f, "STATUS_CONFIRM_FAIL"),
+            Self::StatusEnable => write!(f, "STATUS_ENABLE"),
+            Self::StatusConfirmFailEnd => write!(f, "STATUS_CONFIRM_FAIL_END"),
+            Self::StatusPendingConfirmModify => write!(f, "STATUS_PENDING_CONFIRM_MODIFY"),
+            Self::StatusConfirmModifyFail => write!(f, "STATUS_CONFIRM_MODIFY_FAIL"),
+            Self::StatusLimit => write!(f, "STATUS_LIMIT"),
+            Self::StatusWaitForBpmAudit => write!(f, "STATUS_WAIT_FOR_BPM_AUDIT"),
+            Self::StatusWaitForPublicAuth => write!(f, "STATUS_WAIT_FOR_PUBLIC_AUTH"),
+            Self::StatusSelfServiceUnaudited => write!(f, "STATUS_SELF_SERVICE_UNAUDITED"),
+            Self::StatusContractPending => write!(f, "STATUS_CONTRACT_PENDING"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum AdvertiserInfoField {
+    AdvertiserId,
+    OwnerBcId,
+    Name,
+    Status,
+    Role,
+    RejectionReason,
+    Currency,
+    Timezone,
+    DisplayTimezone,
+    Company,
+    Industry,
+    Address,
+    Country,
+    AdvertiserAccountType,
+    Contacter,
+    Email,
+    CellphoneNumber,
+    TelephoneNumber,
+    Language,
+    LicenseNo,
+    LicenseUrl,
+    Brand,
+    Description,
+    Balance,
+    CreateTime,
+}
+
+impl AdvertiserInfoField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(AdvertiserInfoField::AdvertiserId);
+        set.insert(AdvertiserInfoField::OwnerBcId);
+        set.insert(AdvertiserInfoField::Name);
+        set.insert(AdvertiserInfoField::Status);
+        set.insert(AdvertiserInfoField::Role);
+        set.insert(AdvertiserInfoField::RejectionReason);
+        set.insert(AdvertiserInfoField::Currency);
+        set.insert(AdvertiserInfoField::Timezone);
+        set.insert(AdvertiserInfoField::DisplayTimezone);
+        set.insert(AdvertiserInfoField::Company);
+        set.insert(AdvertiserInfoField::Industry);
+        set.insert(AdvertiserInfoField::Address);
+        set.insert(AdvertiserInfoField::Country);
+        set.insert(AdvertiserInfoField::AdvertiserAccountType);
+        set.insert(AdvertiserInfoField::Contacter);
+        set.insert(AdvertiserInfoField::Email);
+        set.insert(AdvertiserInfoField::CellphoneNumber);
+        set.insert(AdvertiserInfoField::TelephoneNumber);
+        set.insert(AdvertiserInfoField::Language);
+        set.insert(AdvertiserInfoField::LicenseNo);
+        set.insert(AdvertiserInfoField::LicenseUrl);
+        set.insert(AdvertiserInfoField::Brand);
+        set.insert(AdvertiserInfoField::Description);
+        set.insert(AdvertiserInfoField::Balance);
+        set.insert(AdvertiserInfoField::CreateTime);
+        set
+    }
+}
+
+impl std::fmt::Display for AdvertiserInfoField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AdvertiserId => write!(f, "advertiser_id"),
+            Self::OwnerBcId => write!(f, "owner_bc_id"),
+            Self::Name => write!(f, "name"),
+            Self::Status => write!(f, "status"),
+            Self::Role => write!(f, "role"),
+            Self::RejectionReason => write!(f, "rejection_reason"),
+            Self::Currency => write!(f, "currency"),
+            Self::Timezone => write!(f, "timezone"),
+            Self::DisplayTimezone => write!(f, "display_timezone"),
+            Self::Company => write!(f, "company"),
+            Self::Industry => write!(f, "industry"),
+            Self::Address => write!(f, "address"),
+            Self::Country => write!(f, "country"),
+            Self::AdvertiserAccountType => write!(f, "advertiser_account_type"),
+            Self::Contacter => write!(f, "contacter"),
+            Self::Email => write!(f, "email"),
+            Self::CellphoneNumber => write!(f, "cellphone_number"),
+            Self::TelephoneNumber => write!(f, "telephone_number"),
+            Self::Language => write!(f, "language"),
+            Self::LicenseNo => write!(f, "license_no"),
+            Self::LicenseUrl => write!(f, "license_url"),
+            Self::Brand => write!(f, "brand"),
+            Self::Description => write!(f, "description"),
+            Self::Balance => write!(f, "balance"),
+            Self::CreateTime => write!(f, "create_time"),
+        }
+    }
+}
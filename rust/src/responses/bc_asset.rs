@@ -0,0 +1,77 @@
+use crate::apis::get_bc_asset_get::AssetType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BcAsset {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_type: Option<AssetType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_account_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_creation_eligible: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_bc_name: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl BcAsset {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("BcAsset {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum BcAssetField {
+    AssetId,
+    AssetName,
+    AssetType,
+    AdvertiserAccountType,
+    AdvertiserRole,
+    CatalogRole,
+    AdCreationEligible,
+    OwnerBcName,
+}
+
+impl BcAssetField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(BcAssetField::AssetId);
+        set.insert(BcAssetField::AssetName);
+        set.insert(BcAssetField::AssetType);
+        set.insert(BcAssetField::AdvertiserAccountType);
+        set.insert(BcAssetField::AdvertiserRole);
+        set.insert(BcAssetField::CatalogRole);
+        set.insert(BcAssetField::AdCreationEligible);
+        set.insert(BcAssetField::OwnerBcName);
+        set
+    }
+}
+
+impl std::fmt::Display for BcAssetField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AssetId => write!(f, "asset_id"),
+            Self::AssetName => write!(f, "asset_name"),
+            Self::AssetType => write!(f, "asset_type"),
+            Self::AdvertiserAccountType => write!(f, "advertiser_account_type"),
+            Self::AdvertiserRole => write!(f, "advertiser_role"),
+            Self::CatalogRole => write!(f, "catalog_role"),
+            Self::AdCreationEligible => write!(f, "ad_creation_eligible"),
+            Self::OwnerBcName => write!(f, "owner_bc_name"),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::responses::business_center::BcUserRole;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BcMember {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_role: Option<BcUserRole>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext_user_role: Option<ExtUserRole>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl BcMember {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .ext_user_role
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("BcMember {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum BcMemberField {
+    UserId,
+    UserName,
+    UserEmail,
+    RelationStatus,
+    UserRole,
+    ExtUserRole,
+}
+
+impl BcMemberField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(BcMemberField::UserId);
+        set.insert(BcMemberField::UserName);
+        set.insert(BcMemberField::UserEmail);
+        set.insert(BcMemberField::RelationStatus);
+        set.insert(BcMemberField::UserRole);
+        set.insert(BcMemberField::ExtUserRole);
+        set
+    }
+}
+
+impl std::fmt::Display for BcMemberField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UserId => write!(f, "user_id"),
+            Self::UserName => write!(f, "user_name"),
+            Self::UserEmail => write!(f, "user_email"),
+            Self::RelationStatus => write!(f, "relation_status"),
+            Self::UserRole => write!(f, "user_role"),
+            Self::ExtUserRole => write!(f, "ext_user_role"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ExtUserRole {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finance_role: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ExtUserRole {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ExtUserRole {:?}", self.extra);
+        }
+        res
+    }
+}
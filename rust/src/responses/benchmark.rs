@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Benchmark {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_category: Option<BusinessCategory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followers_count: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_views: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_views: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub likes: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shares: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engagement_rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_posts_per_week: Option<f64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Benchmark {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Benchmark {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum BusinessCategory {
+    #[serde(rename = "ART_AND_CRAFTS")]
+    #[default]
+    ArtAndCrafts,
+    #[serde(rename = "AUTOMOTIVE_AND_TRANSPORTATION")]
+    AutomotiveAndTransportation,
+    #[serde(rename = "BABY")]
+    Baby,
+    #[serde(rename = "BEAUTY")]
+    Beauty,
+    #[serde(rename = "CLOTHING_AND_ACCESSORIES")]
+    ClothingAndAccessories,
+    #[serde(rename = "EDUCATION_AND_TRAINING")]
+    EducationAndTraining,
+    #[serde(rename = "ELECTRONICS")]
+    Electronics,
+    #[serde(rename = "FINANCE_AND_INVESTING")]
+    FinanceAndInvesting,
+    #[serde(rename = "FOOD_AND_BEVERAGE")]
+    FoodAndBeverage,
+    #[serde(rename = "GAMING")]
+    Gaming,
+    #[serde(rename = "HEALTH_AND_WELLNESS")]
+    HealthAndWellness,
+    #[serde(rename = "HOME_FURNITURE_AND_APPLIANCES")]
+    HomeFurnitureAndAppliances,
+    #[serde(rename = "MACHINERY_AND_EQUIPMENT")]
+    MachineryAndEquipment,
+    #[serde(rename = "MEDIA_AND_ENTERTAINMENT")]
+    MediaAndEntertainment,
+    #[serde(rename = "PERSONAL_BLOG")]
+    PersonalBlog,
+    #[serde(rename = "PETS")]
+    Pets,
+    #[serde(rename = "PROFESSIONAL_SERVICES")]
+    ProfessionalServices,
+    #[serde(rename = "PUBLIC_ADMINISTRATION")]
+    PublicAdministration,
+    #[serde(rename = "REAL_ESTATE")]
+    RealEstate,
+    #[serde(rename = "RESTAURANTS_AND_BARS")]
+    RestaurantsAndBars,
+    #[serde(rename = "SHOPPING_AND_RETAIL")]
+    ShoppingAndRetail,
+    #[serde(rename = "SOFTWARE_AND_APPS")]
+    SoftwareAndApps,
+    #[serde(rename = "SPORTS_FITNESS_AND_OUTDOORS")]
+    SportsFitnessAndOutdoors,
+    #[serde(rename = "TRAVEL_AND_TOURISM")]
+    TravelAndTourism,
+    #[serde(rename = "OTHERS")]
+    Others,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for BusinessCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ArtAndCrafts => write!(f, "ART_AND_CRAFTS"),
+            Self::AutomotiveAndTransportation => write!(f, "AUTOMOTIVE_AND_TRANSPORTATION"),
+            Self::Baby => write!(f, "BABY"),
+            Self::Beauty => write!(f, "BEAUTY"),
+            Self::ClothingAndAccessories => write!(f, "CLOTHING_AND_ACCESSORIES"),
+            Self::EducationAndTraining => write!(f, "EDUCATION_AND_TRAINING"),
+            Self::Electronics => write!(f, "ELECTRONICS"),
+            Self::FinanceAndInvesting => write!(f, "FINANCE_AND_INVESTING"),
+            Self::FoodAndBeverage => write!(f, "FOOD_AND_BEVERAGE"),
+            Self::Gaming => write!(f, "GAMING"),
+            Self::HealthAndWellness => write!(f, "HEALTH_AND_WELLNESS"),
+            Self::HomeFurnitureAndAppliances => write!(f, "HOME_FURNITURE_AND_APPLIANCES"),
+            Self::MachineryAndEquipment => write!(f, "MACHINERY_AND_EQUIPMENT"),
+            Self::MediaAndEntertainment => write!(f, "MEDIA_AND_ENTERTAINMENT"),
+            Self::PersonalBlog => write!(f, "PERSONAL_BLOG"),
+            Self::Pets => write!(f, "PETS"),
+            Self::ProfessionalServices => write!(f, "PROFESSIONAL_SERVICES"),
+            Self::PublicAdministration => write!(f, "PUBLIC_ADMINISTRATION"),
+            Self::RealEstate => write!(f, "REAL_ESTATE"),
+            Self::RestaurantsAndBars => write!(f, "RESTAURANTS_AND_BARS"),
+            Self::ShoppingAndRetail => write!(f, "SHOPPING_AND_RETAIL"),
+            Self::SoftwareAndApps => write!(f, "SOFTWARE_AND_APPS"),
+            Self::SportsFitnessAndOutdoors => write!(f, "SPORTS_FITNESS_AND_OUTDOORS"),
+            Self::TravelAndTourism => write!(f, "TRAVEL_AND_TOURISM"),
+            Self::Others => write!(f, "OTHERS"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum BenchmarkField {
+    BusinessCategory,
+    FollowersCount,
+    ProfileViews,
+    VideoViews,
+    Likes,
+    Comments,
+    Shares,
+    EngagementRate,
+    AveragePostsPerWeek,
+}
+
+impl BenchmarkField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(BenchmarkField::BusinessCategory);
+        set.insert(BenchmarkField::FollowersCount);
+        set.insert(BenchmarkField::ProfileViews);
+        set.insert(BenchmarkField::VideoViews);
+        set.insert(BenchmarkField::Likes);
+        set.insert(BenchmarkField::Comments);
+        set.insert(BenchmarkField::Shares);
+        set.insert(BenchmarkField::EngagementRate);
+        set.insert(BenchmarkField::AveragePostsPerWeek);
+        set
+    }
+}
+
+impl std::fmt::Display for BenchmarkField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::BusinessCategory => write!(f, "business_category"),
+            Self::FollowersCount => write!(f, "followers_count"),
+            Self::ProfileViews => write!(f, "profile_views"),
+            Self::VideoViews => write!(f, "video_views"),
+            Self::Likes => write!(f, "likes"),
+            Self::Comments => write!(f, "comments"),
+            Self::Shares => write!(f, "shares"),
+            Self::EngagementRate => write!(f, "engagement_rate"),
+            Self::AveragePostsPerWeek => write!(f, "average_posts_per_week"),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BusinessCenter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bc_info: Option<BcInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_role: Option<BcUserRole>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext_user_role: Option<ExtUserRole>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl BusinessCenter {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .bc_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .ext_user_role
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("BusinessCenter {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum BcUserRole {
+    #[serde(rename = "ADMIN")]
+    #[default]
+    Admin,
+    #[serde(rename = "STANDARD")]
+    Standard,
+}
+
+impl std::fmt::Display for BcUserRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Admin => write!(f, "ADMIN"),
+            Self::Standard => write!(f, "STANDARD"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum BusinessCenterField {
+    BcInfo,
+    UserRole,
+    ExtUserRole,
+}
+
+impl BusinessCenterField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(BusinessCenterField::BcInfo);
+        set.insert(BusinessCenterField::UserRole);
+        set.insert(BusinessCenterField::ExtUserRole);
+        set
+    }
+}
+
+impl std::fmt::Display for BusinessCenterField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::BcInfo => write!(f, "bc_info"),
+            Self::UserRole => write!(f, "user_role"),
+            Self::ExtUserRole => write!(f, "ext_user_role"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BcInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bc_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registered_area: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<BcStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<BcType>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl BcInfo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("BcInfo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum BcStatus {
+    #[serde(rename = "REVIEWING")]
+    #[default]
+    Reviewing,
+    #[serde(rename = "DENY")]
+    Deny,
+    #[serde(rename = "ENABLE")]
+    Enable,
+    #[serde(rename = "PUNISH")]
+    Punish,
+}
+
+impl std::fmt::Display for BcStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Reviewing => write!(f, "REVIEWING"),
+            Self::Deny => write!(f, "DENY"),
+            Self::Enable => write!(f, "ENABLE"),
+            Self::Punish => write!(f, "PUNISH"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum BcType {
+    #[serde(rename = "NORMAL")]
+    #[default]
+    Normal,
+    #[serde(rename = "DIRECT")]
+    Direct,
+    #[serde(rename = "AGENCY")]
+    Agency,
+    #[serde(rename = "SELF_SERVICE")]
+    SelfService,
+    #[serde(rename = "SELF_SERVICE_AGENCY")]
+    SelfServiceAgency,
+}
+
+impl std::fmt::Display for BcType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "NORMAL"),
+            Self::Direct => write!(f, "DIRECT"),
+            Self::Agency => write!(f, "AGENCY"),
+            Self::SelfService => write!(f, "SELF_SERVICE"),
+            Self::SelfServiceAgency => write!(f, "SELF_SERVICE_AGENCY"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ExtUserRole {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finance_role: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ExtUserRole {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ExtUserRole {:?}", self.extra);
+        }
+        res
+    }
+}
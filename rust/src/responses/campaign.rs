@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Campaign {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub objective_type: Option<ObjectiveType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_mode: Option<BudgetMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_optimize_on: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_status: Option<OperationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub special_industries: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_new_structure: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_smart_performance_campaign: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modify_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Campaign {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Campaign {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ObjectiveType {
+    #[serde(rename = "APP_PROMOTION")]
+    #[default]
+    AppPromotion,
+    #[serde(rename = "WEB_CONVERSIONS")]
+    WebConversions,
+    #[serde(rename = "REACH")]
+    Reach,
+    #[serde(rename = "TRAFFIC")]
+    Traffic,
+    #[serde(rename = "VIDEO_VIEWS")]
+    VideoViews,
+    #[serde(rename = "ENGAGEMENT")]
+    Engagement,
+    #[serde(rename = "LEAD_GENERATION")]
+    LeadGeneration,
+    #[serde(rename = "PRODUCT_SALES")]
+    ProductSales,
+    #[serde(rename = "RF_REACH")]
+    RfReach,
+    #[serde(rename = "APP_INSTALL")]
+    AppInstall,
+    #[serde(rename = "CONVERSIONS")]
+    Conversions,
+    #[serde(rename = "CATALOG_SALES")]
+    CatalogSales,
+    #[serde(rename = "SHOP_PURCHASES")]
+    ShopPurchases,
+}
+
+impl std::fmt::Display for ObjectiveType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AppPromotion => write!(f, "APP_PROMOTION"),
+            Self::WebConversions => write!(f, "WEB_CONVERSIONS"),
+            Self::Reach => write!(f, "REACH"),
+            Self::Traffic => write!(f, "TRAFFIC"),
+            Self::VideoViews => write!(f, "VIDEO_VIEWS"),
+            Self::Engagement => write!(f, "ENGAGEMENT"),
+            Self::LeadGeneration => write!(f, "LEAD_GENERATION"),
+            Self::ProductSales => write!(f, "PRODUCT_SALES"),
+            Self::RfReach => write!(f, "RF_REACH"),
+            Self::AppInstall => write!(f, "APP_INSTALL"),
+            Self::Conversions => write!(f, "CONVERSIONS"),
+            Self::CatalogSales => write!(f, "CATALOG_SALES"),
+            Self::ShopPurchases => write!(f, "SHOP_PURCHASES"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum BudgetMode {
+    #[serde(rename = "BUDGET_MODE_INFINITE")]
+    #[default]
+    BudgetModeInfinite,
+    #[serde(rename = "BUDGET_MODE_DAY")]
+    BudgetModeDay,
+    #[serde(rename = "BUDGET_MODE_TOTAL")]
+    BudgetModeTotal,
+    #[serde(rename = "BUDGET_MODE_DYNAMIC_DAILY_BUDGET")]
+    BudgetModeDynamicDailyBudget,
+}
+
+impl std::fmt::Display for BudgetMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::BudgetModeInfinite => write!(f, "BUDGET_MODE_INFINITE"),
+            Self::BudgetModeDay => write!(f, "BUDGET_MODE_DAY"),
+            Self::BudgetModeTotal => write!(f, "BUDGET_MODE_TOTAL"),
+            Self::BudgetModeDynamicDailyBudget => write!(f, "BUDGET_MODE_DYNAMIC_DAILY_BUDGET"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum OperationStatus {
+    #[serde(rename = "ENABLE")]
+    #[default]
+    Enable,
+    #[serde(rename = "DISABLE")]
+    Disable,
+    #[serde(rename = "DELETE")]
+    Delete,
+}
+
+impl std::fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Enable => write!(f, "ENABLE"),
+            Self::Disable => write!(f, "DISABLE"),
+            Self::Delete => write!(f, "DELETE"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum CampaignField {
+    AdvertiserId,
+    CampaignId,
+    CampaignName,
+    CampaignType,
+    ObjectiveType,
+    BudgetMode,
+    Budget,
+    BudgetOptimizeOn,
+    OperationStatus,
+    SecondaryStatus,
+    SpecialIndustries,
+    IsNewStructure,
+    IsSmartPerformanceCampaign,
+    CreateTime,
+    ModifyTime,
+}
+
+impl CampaignField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(CampaignField::AdvertiserId);
+        set.insert(CampaignField::CampaignId);
+        set.insert(CampaignField::CampaignName);
+        set.insert(CampaignField::CampaignType);
+        set.insert(CampaignField::ObjectiveType);
+        set.insert(CampaignField::BudgetMode);
+        set.insert(CampaignField::Budget);
+        set.insert(CampaignField::BudgetOptimizeOn);
+        set.insert(CampaignField::OperationStatus);
+        set.insert(CampaignField::SecondaryStatus);
+        set.insert(CampaignField::SpecialIndustries);
+        set.insert(CampaignField::IsNewStructure);
+        set.insert(CampaignField::IsSmartPerformanceCampaign);
+        set.insert(CampaignField::CreateTime);
+        set.insert(CampaignField::ModifyTime);
+        set
+    }
+}
+
+impl std::fmt::Display for CampaignField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AdvertiserId => write!(f, "advertiser_id"),
+            Self::CampaignId => write!(f, "campaign_id"),
+            Self::CampaignName => write!(f, "campaign_name"),
+            Self::CampaignType => write!(f, "campaign_type"),
+            Self::ObjectiveType => write!(f, "objective_type"),
+            Self::BudgetMode => write!(f, "budget_mode"),
+            Self::Budget => write!(f, "budget"),
+            Self::BudgetOptimizeOn => write!(f, "budget_optimize_on"),
+            Self::OperationStatus => write!(f, "operation_status"),
+            Self::SecondaryStatus => write!(f, "secondary_status"),
+            Self::SpecialIndustries => write!(f, "special_industries"),
+            Self::IsNewStructure => write!(f, "is_new_structure"),
+            Self::IsSmartPerformanceCampaign => write!(f, "is_smart_performance_campaign"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::ModifyTime => write!(f, "modify_time"),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::apis::post_catalog_create::CatalogType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Catalog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_type: Option<CatalogType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Catalog {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Catalog {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum CatalogField {
+    CatalogId,
+    CatalogName,
+    CatalogType,
+    RegionCode,
+    Currency,
+    CreateTime,
+    UpdateTime,
+}
+
+impl CatalogField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(CatalogField::CatalogId);
+        set.insert(CatalogField::CatalogName);
+        set.insert(CatalogField::CatalogType);
+        set.insert(CatalogField::RegionCode);
+        set.insert(CatalogField::Currency);
+        set.insert(CatalogField::CreateTime);
+        set.insert(CatalogField::UpdateTime);
+        set
+    }
+}
+
+impl std::fmt::Display for CatalogField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::CatalogId => write!(f, "catalog_id"),
+            Self::CatalogName => write!(f, "catalog_name"),
+            Self::CatalogType => write!(f, "catalog_type"),
+            Self::RegionCode => write!(f, "region_code"),
+            Self::Currency => write!(f, "currency"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::UpdateTime => write!(f, "update_time"),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::apis::post_catalog_feed_create::ScheduleParam;
+use crate::apis::post_catalog_product_file::UpdateMode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CatalogFeed {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_mode: Option<UpdateMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_update_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_update_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_param: Option<ScheduleParam>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl CatalogFeed {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("CatalogFeed {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum CatalogFeedField {
+    FeedId,
+    FeedName,
+    UpdateMode,
+    Status,
+    NextUpdateTime,
+    LastUpdateStatus,
+    ScheduleParam,
+}
+
+impl CatalogFeedField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(CatalogFeedField::FeedId);
+        set.insert(CatalogFeedField::FeedName);
+        set.insert(CatalogFeedField::UpdateMode);
+        set.insert(CatalogFeedField::Status);
+        set.insert(CatalogFeedField::NextUpdateTime);
+        set.insert(CatalogFeedField::LastUpdateStatus);
+        set.insert(CatalogFeedField::ScheduleParam);
+        set
+    }
+}
+
+impl std::fmt::Display for CatalogFeedField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::FeedId => write!(f, "feed_id"),
+            Self::FeedName => write!(f, "feed_name"),
+            Self::UpdateMode => write!(f, "update_mode"),
+            Self::Status => write!(f, "status"),
+            Self::NextUpdateTime => write!(f, "next_update_time"),
+            Self::LastUpdateStatus => write!(f, "last_update_status"),
+            Self::ScheduleParam => write!(f, "schedule_param"),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CatalogProduct {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_group_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit: Option<Audit>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl CatalogProduct {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .audit
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("CatalogProduct {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum CatalogProductField {
+    ProductId,
+    SkuId,
+    ItemGroupId,
+    Title,
+    Status,
+    ActiveStatus,
+    Audit,
+}
+
+impl CatalogProductField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(CatalogProductField::ProductId);
+        set.insert(CatalogProductField::SkuId);
+        set.insert(CatalogProductField::ItemGroupId);
+        set.insert(CatalogProductField::Title);
+        set.insert(CatalogProductField::Status);
+        set.insert(CatalogProductField::ActiveStatus);
+        set.insert(CatalogProductField::Audit);
+        set
+    }
+}
+
+impl std::fmt::Display for CatalogProductField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ProductId => write!(f, "product_id"),
+            Self::SkuId => write!(f, "sku_id"),
+            Self::ItemGroupId => write!(f, "item_group_id"),
+            Self::Title => write!(f, "title"),
+            Self::Status => write!(f, "status"),
+            Self::ActiveStatus => write!(f, "active_status"),
+            Self::Audit => write!(f, "audit"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Audit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audit_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reject_info: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Audit {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Audit {:?}", self.extra);
+        }
+        res
+    }
+}
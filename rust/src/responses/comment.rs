@@ -15,6 +15,7 @@ pub struct Comment {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub create_time: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "content")]
     pub text: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub likes: Option<i64>,
@@ -29,6 +30,7 @@ pub struct Comment {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "user_name")]
     pub username: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub profile_image: Option<String>,
@@ -36,6 +38,16 @@ pub struct Comment {
     pub parent_comment_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_list: Option<Vec<Reply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_type: Option<CommentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adgroup_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_id: Option<String>,
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -73,6 +85,24 @@ impl std::fmt::Display for Status {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum CommentType {
+    #[serde(rename = "COMMENT")]
+    #[default]
+    Comment,
+    #[serde(rename = "REPLY")]
+    Reply,
+}
+
+impl std::fmt::Display for CommentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Comment => write!(f, "COMMENT"),
+            Self::Reply => write!(f, "REPLY"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum CommentField {
     CommentId,
@@ -91,6 +121,11 @@ pub enum CommentField {
     ProfileImage,
     ParentCommentId,
     ReplyList,
+    CommentType,
+    AdId,
+    AdgroupId,
+    CampaignId,
+    AdvertiserId,
 }
 
 impl CommentField {
@@ -112,6 +147,11 @@ impl CommentField {
         set.insert(CommentField::ProfileImage);
         set.insert(CommentField::ParentCommentId);
         set.insert(CommentField::ReplyList);
+        set.insert(CommentField::CommentType);
+        set.insert(CommentField::AdId);
+        set.insert(CommentField::AdgroupId);
+        set.insert(CommentField::CampaignId);
+        set.insert(CommentField::AdvertiserId);
         set
     }
 }
@@ -135,6 +175,11 @@ impl std::fmt::Display for CommentField {
             Self::ProfileImage => write!(f, "profile_image"),
             Self::ParentCommentId => write!(f, "parent_comment_id"),
             Self::ReplyList => write!(f, "reply_list"),
+            Self::CommentType => write!(f, "comment_type"),
+            Self::AdId => write!(f, "ad_id"),
+            Self::AdgroupId => write!(f, "adgroup_id"),
+            Self::CampaignId => write!(f, "campaign_id"),
+            Self::AdvertiserId => write!(f, "advertiser_id"),
         }
     }
 }
@@ -0,0 +1,95 @@
+use crate::responses::adlib_ad::AdlibVideo;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CommercialContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_timestamp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Creator>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub videos: Option<Vec<AdlibVideo>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl CommercialContent {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .creator
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("CommercialContent {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum CommercialContentField {
+    Id,
+    CreateDate,
+    CreateTimestamp,
+    Label,
+    BrandNames,
+    Creator,
+    Videos,
+}
+
+impl CommercialContentField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(CommercialContentField::Id);
+        set.insert(CommercialContentField::CreateDate);
+        set.insert(CommercialContentField::CreateTimestamp);
+        set.insert(CommercialContentField::Label);
+        set.insert(CommercialContentField::BrandNames);
+        set.insert(CommercialContentField::Creator);
+        set.insert(CommercialContentField::Videos);
+        set
+    }
+}
+
+impl std::fmt::Display for CommercialContentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Id => write!(f, "id"),
+            Self::CreateDate => write!(f, "create_date"),
+            Self::CreateTimestamp => write!(f, "create_timestamp"),
+            Self::Label => write!(f, "label"),
+            Self::BrandNames => write!(f, "brand_names"),
+            Self::Creator => write!(f, "creator"),
+            Self::Videos => write!(f, "videos"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Creator {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Creator {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Creator {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CustomAudience {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_num: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AudienceStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_valid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_expiring: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_creator: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calculate_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl CustomAudience {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("CustomAudience {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum AudienceStatus {
+    #[serde(rename = "CALCULATING")]
+    #[default]
+    Calculating,
+    #[serde(rename = "VALID")]
+    Valid,
+    #[serde(rename = "INVALID")]
+    Invalid,
+    #[serde(rename = "EXPIRED")]
+    Expired,
+    #[serde(rename = "FAILED")]
+    Failed,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for AudienceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Calculating => write!(f, "CALCULATING"),
+            Self::Valid => write!(f, "VALID"),
+            Self::Invalid => write!(f, "INVALID"),
+            Self::Expired => write!(f, "EXPIRED"),
+            Self::Failed => write!(f, "FAILED"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum CustomAudienceField {
+    AudienceId,
+    Name,
+    AudienceType,
+    CoverNum,
+    Status,
+    IsValid,
+    IsExpiring,
+    IsCreator,
+    Shared,
+    CalculateType,
+    CreateTime,
+    ExpiredTime,
+}
+
+impl CustomAudienceField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(CustomAudienceField::AudienceId);
+        set.insert(CustomAudienceField::Name);
+        set.insert(CustomAudienceField::AudienceType);
+        set.insert(CustomAudienceField::CoverNum);
+        set.insert(CustomAudienceField::Status);
+        set.insert(CustomAudienceField::IsValid);
+        set.insert(CustomAudienceField::IsExpiring);
+        set.insert(CustomAudienceField::IsCreator);
+        set.insert(CustomAudienceField::Shared);
+        set.insert(CustomAudienceField::CalculateType);
+        set.insert(CustomAudienceField::CreateTime);
+        set.insert(CustomAudienceField::ExpiredTime);
+        set
+    }
+}
+
+impl std::fmt::Display for CustomAudienceField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AudienceId => write!(f, "audience_id"),
+            Self::Name => write!(f, "name"),
+            Self::AudienceType => write!(f, "audience_type"),
+            Self::CoverNum => write!(f, "cover_num"),
+            Self::Status => write!(f, "status"),
+            Self::IsValid => write!(f, "is_valid"),
+            Self::IsExpiring => write!(f, "is_expiring"),
+            Self::IsCreator => write!(f, "is_creator"),
+            Self::Shared => write!(f, "shared"),
+            Self::CalculateType => write!(f, "calculate_type"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::ExpiredTime => write!(f, "expired_time"),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::responses::ad_group::OperatingSystem;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeviceModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_model_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_model_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_type: Option<OperatingSystem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub child_device_ids: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl DeviceModel {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("DeviceModel {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum DeviceModelField {
+    DeviceModelId,
+    DeviceModelName,
+    OsType,
+    IsActive,
+    Level,
+    ChildDeviceIds,
+}
+
+impl DeviceModelField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(DeviceModelField::DeviceModelId);
+        set.insert(DeviceModelField::DeviceModelName);
+        set.insert(DeviceModelField::OsType);
+        set.insert(DeviceModelField::IsActive);
+        set.insert(DeviceModelField::Level);
+        set.insert(DeviceModelField::ChildDeviceIds);
+        set
+    }
+}
+
+impl std::fmt::Display for DeviceModelField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DeviceModelId => write!(f, "device_model_id"),
+            Self::DeviceModelName => write!(f, "device_model_name"),
+            Self::OsType => write!(f, "os_type"),
+            Self::IsActive => write!(f, "is_active"),
+            Self::Level => write!(f, "level"),
+            Self::ChildDeviceIds => write!(f, "child_device_ids"),
+        }
+    }
+}
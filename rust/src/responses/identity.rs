@@ -0,0 +1,92 @@
+use crate::responses::ad::IdentityType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Identity {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_type: Option<IdentityType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_authorized_bc_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pull_video: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_push_video: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_use_live_ads: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_message: Option<bool>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Identity {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Identity {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum IdentityField {
+    IdentityId,
+    IdentityType,
+    IdentityAuthorizedBcId,
+    AvailableStatus,
+    DisplayName,
+    UserName,
+    ProfileImage,
+    CanPullVideo,
+    CanPushVideo,
+    CanUseLiveAds,
+    CanManageMessage,
+}
+
+impl IdentityField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(IdentityField::IdentityId);
+        set.insert(IdentityField::IdentityType);
+        set.insert(IdentityField::IdentityAuthorizedBcId);
+        set.insert(IdentityField::AvailableStatus);
+        set.insert(IdentityField::DisplayName);
+        set.insert(IdentityField::UserName);
+        set.insert(IdentityField::ProfileImage);
+        set.insert(IdentityField::CanPullVideo);
+        set.insert(IdentityField::CanPushVideo);
+        set.insert(IdentityField::CanUseLiveAds);
+        set.insert(IdentityField::CanManageMessage);
+        set
+    }
+}
+
+impl std::fmt::Display for IdentityField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::IdentityId => write!(f, "identity_id"),
+            Self::IdentityType => write!(f, "identity_type"),
+            Self::IdentityAuthorizedBcId => write!(f, "identity_authorized_bc_id"),
+            Self::AvailableStatus => write!(f, "available_status"),
+            Self::DisplayName => write!(f, "display_name"),
+            Self::UserName => write!(f, "user_name"),
+            Self::ProfileImage => write!(f, "profile_image"),
+            Self::CanPullVideo => write!(f, "can_pull_video"),
+            Self::CanPushVideo => write!(f, "can_push_video"),
+            Self::CanUseLiveAds => write!(f, "can_use_live_ads"),
+            Self::CanManageMessage => write!(f, "can_manage_message"),
+        }
+    }
+}
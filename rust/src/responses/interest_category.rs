@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InterestCategory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_category_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placements: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl InterestCategory {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("InterestCategory {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum InterestCategoryField {
+    Id,
+    Name,
+    Level,
+    SubCategoryIds,
+    Placements,
+}
+
+impl InterestCategoryField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(InterestCategoryField::Id);
+        set.insert(InterestCategoryField::Name);
+        set.insert(InterestCategoryField::Level);
+        set.insert(InterestCategoryField::SubCategoryIds);
+        set.insert(InterestCategoryField::Placements);
+        set
+    }
+}
+
+impl std::fmt::Display for InterestCategoryField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Id => write!(f, "id"),
+            Self::Name => write!(f, "name"),
+            Self::Level => write!(f, "level"),
+            Self::SubCategoryIds => write!(f, "sub_category_ids"),
+            Self::Placements => write!(f, "placements"),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Lead {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adgroup_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adgroup_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_answers: Option<std::collections::HashMap<String, String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Lead {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Lead {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum LeadField {
+    LeadId,
+    CreateTime,
+    PageId,
+    PageName,
+    AdId,
+    AdName,
+    AdgroupId,
+    AdgroupName,
+    CampaignId,
+    CampaignName,
+    Name,
+    Email,
+    PhoneNumber,
+}
+
+impl LeadField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(LeadField::LeadId);
+        set.insert(LeadField::CreateTime);
+        set.insert(LeadField::PageId);
+        set.insert(LeadField::PageName);
+        set.insert(LeadField::AdId);
+        set.insert(LeadField::AdName);
+        set.insert(LeadField::AdgroupId);
+        set.insert(LeadField::AdgroupName);
+        set.insert(LeadField::CampaignId);
+        set.insert(LeadField::CampaignName);
+        set.insert(LeadField::Name);
+        set.insert(LeadField::Email);
+        set.insert(LeadField::PhoneNumber);
+        set
+    }
+}
+
+impl std::fmt::Display for LeadField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::LeadId => write!(f, "lead_id"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::PageId => write!(f, "page_id"),
+            Self::PageName => write!(f, "page_name"),
+            Self::AdId => write!(f, "ad_id"),
+            Self::AdName => write!(f, "ad_name"),
+            Self::AdgroupId => write!(f, "adgroup_id"),
+            Self::AdgroupName => write!(f, "adgroup_name"),
+            Self::CampaignId => write!(f, "campaign_id"),
+            Self::CampaignName => write!(f, "campaign_name"),
+            Self::Name => write!(f, "name"),
+            Self::Email => write!(f, "email"),
+            Self::PhoneNumber => write!(f, "phone_number"),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LeadForm {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl LeadForm {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("LeadForm {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum LeadFormField {
+    PageId,
+    Title,
+    Status,
+    BusinessType,
+    CreateTime,
+    UpdateTime,
+}
+
+impl LeadFormField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(LeadFormField::PageId);
+        set.insert(LeadFormField::Title);
+        set.insert(LeadFormField::Status);
+        set.insert(LeadFormField::BusinessType);
+        set.insert(LeadFormField::CreateTime);
+        set.insert(LeadFormField::UpdateTime);
+        set
+    }
+}
+
+impl std::fmt::Display for LeadFormField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PageId => write!(f, "page_id"),
+            Self::Title => write!(f, "title"),
+            Self::Status => write!(f, "status"),
+            Self::BusinessType => write!(f, "business_type"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::UpdateTime => write!(f, "update_time"),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MentionedComment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub likes: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replies: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_share_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl MentionedComment {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("MentionedComment {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum MentionedCommentField {
+    CommentId,
+    VideoId,
+    CreateTime,
+    Text,
+    Username,
+    DisplayName,
+    ProfileImage,
+    Likes,
+    Replies,
+    VideoShareUrl,
+}
+
+impl MentionedCommentField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(MentionedCommentField::CommentId);
+        set.insert(MentionedCommentField::VideoId);
+        set.insert(MentionedCommentField::CreateTime);
+        set.insert(MentionedCommentField::Text);
+        set.insert(MentionedCommentField::Username);
+        set.insert(MentionedCommentField::DisplayName);
+        set.insert(MentionedCommentField::ProfileImage);
+        set.insert(MentionedCommentField::Likes);
+        set.insert(MentionedCommentField::Replies);
+        set.insert(MentionedCommentField::VideoShareUrl);
+        set
+    }
+}
+
+impl std::fmt::Display for MentionedCommentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::CommentId => write!(f, "comment_id"),
+            Self::VideoId => write!(f, "video_id"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::Text => write!(f, "text"),
+            Self::Username => write!(f, "username"),
+            Self::DisplayName => write!(f, "display_name"),
+            Self::ProfileImage => write!(f, "profile_image"),
+            Self::Likes => write!(f, "likes"),
+            Self::Replies => write!(f, "replies"),
+            Self::VideoShareUrl => write!(f, "video_share_url"),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MentionedVideo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embed_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_views: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub likes: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shares: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_code: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl MentionedVideo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("MentionedVideo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum MentionedVideoField {
+    ItemId,
+    CreateTime,
+    Username,
+    DisplayName,
+    ProfileImage,
+    Caption,
+    ThumbnailUrl,
+    ShareUrl,
+    EmbedUrl,
+    VideoViews,
+    Likes,
+    Comments,
+    Shares,
+    RegionCode,
+}
+
+impl MentionedVideoField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(MentionedVideoField::ItemId);
+        set.insert(MentionedVideoField::CreateTime);
+        set.insert(MentionedVideoField::Username);
+        set.insert(MentionedVideoField::DisplayName);
+        set.insert(MentionedVideoField::ProfileImage);
+        set.insert(MentionedVideoField::Caption);
+        set.insert(MentionedVideoField::ThumbnailUrl);
+        set.insert(MentionedVideoField::ShareUrl);
+        set.insert(MentionedVideoField::EmbedUrl);
+        set.insert(MentionedVideoField::VideoViews);
+        set.insert(MentionedVideoField::Likes);
+        set.insert(MentionedVideoField::Comments);
+        set.insert(MentionedVideoField::Shares);
+        set.insert(MentionedVideoField::RegionCode);
+        set
+    }
+}
+
+impl std::fmt::Display for MentionedVideoField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ItemId => write!(f, "item_id"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::Username => write!(f, "username"),
+            Self::DisplayName => write!(f, "display_name"),
+            Self::ProfileImage => write!(f, "profile_image"),
+            Self::Caption => write!(f, "caption"),
+            Self::ThumbnailUrl => write!(f, "thumbnail_url"),
+            Self::ShareUrl => write!(f, "share_url"),
+            Self::EmbedUrl => write!(f, "embed_url"),
+            Self::VideoViews => write!(f, "video_views"),
+            Self::Likes => write!(f, "likes"),
+            Self::Comments => write!(f, "comments"),
+            Self::Shares => write!(f, "shares"),
+            Self::RegionCode => write!(f, "region_code"),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OEmbed {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_unique_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embed_product_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embed_type: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl OEmbed {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("OEmbed {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum OEmbedField {
+    Version,
+    Type,
+    Title,
+    AuthorUrl,
+    AuthorName,
+    AuthorUniqueId,
+    Width,
+    Height,
+    Html,
+    ThumbnailUrl,
+    ThumbnailWidth,
+    ThumbnailHeight,
+    ProviderUrl,
+    ProviderName,
+    EmbedProductId,
+    EmbedType,
+}
+
+impl OEmbedField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(OEmbedField::Version);
+        set.insert(OEmbedField::Type);
+        set.insert(OEmbedField::Title);
+        set.insert(OEmbedField::AuthorUrl);
+        set.insert(OEmbedField::AuthorName);
+        set.insert(OEmbedField::AuthorUniqueId);
+        set.insert(OEmbedField::Width);
+        set.insert(OEmbedField::Height);
+        set.insert(OEmbedField::Html);
+        set.insert(OEmbedField::ThumbnailUrl);
+        set.insert(OEmbedField::ThumbnailWidth);
+        set.insert(OEmbedField::ThumbnailHeight);
+        set.insert(OEmbedField::ProviderUrl);
+        set.insert(OEmbedField::ProviderName);
+        set.insert(OEmbedField::EmbedProductId);
+        set.insert(OEmbedField::EmbedType);
+        set
+    }
+}
+
+impl std::fmt::Display for OEmbedField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Version => write!(f, "version"),
+            Self::Type => write!(f, "type"),
+            Self::Title => write!(f, "title"),
+            Self::AuthorUrl => write!(f, "author_url"),
+            Self::AuthorName => write!(f, "author_name"),
+            Self::AuthorUniqueId => write!(f, "author_unique_id"),
+            Self::Width => write!(f, "width"),
+            Self::Height => write!(f, "height"),
+            Self::Html => write!(f, "html"),
+            Self::ThumbnailUrl => write!(f, "thumbnail_url"),
+            Self::ThumbnailWidth => write!(f, "thumbnail_width"),
+            Self::ThumbnailHeight => write!(f, "thumbnail_height"),
+            Self::ProviderUrl => write!(f, "provider_url"),
+            Self::ProviderName => write!(f, "provider_name"),
+            Self::EmbedProductId => write!(f, "embed_product_id"),
+            Self::EmbedType => write!(f, "embed_type"),
+        }
+    }
+}
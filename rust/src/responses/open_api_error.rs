@@ -0,0 +1,68 @@
+use crate::error::Error;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenApiError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl OpenApiError {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("OpenApiError {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum OpenApiErrorField {
+    Code,
+    Message,
+    LogId,
+}
+
+impl OpenApiErrorField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(OpenApiErrorField::Code);
+        set.insert(OpenApiErrorField::Message);
+        set.insert(OpenApiErrorField::LogId);
+        set
+    }
+}
+
+impl std::fmt::Display for OpenApiErrorField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Code => write!(f, "code"),
+            Self::Message => write!(f, "message"),
+            Self::LogId => write!(f, "log_id"),
+        }
+    }
+}
+
+// code が "ok" 以外の場合はエラーにする
+pub(crate) fn check_open_api_error(
+    error: Option<OpenApiError>,
+    status_code: StatusCode,
+) -> Result<(), Error> {
+    match error {
+        Some(error) if error.code.as_deref().unwrap_or("ok") != "ok" => Err(Error::OpenApi(
+            error.code.unwrap_or_default(),
+            error.message.unwrap_or_default(),
+            status_code,
+        )),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,156 @@
+use crate::oauth::TiktokScope;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenUser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub union_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url_100: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_large_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_deep_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follower_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub following_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub likes_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_count: Option<i64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl OpenUser {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("OpenUser {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum OpenUserField {
+    OpenId,
+    UnionId,
+    AvatarUrl,
+    AvatarUrl100,
+    AvatarLargeUrl,
+    DisplayName,
+    BioDescription,
+    ProfileDeepLink,
+    IsVerified,
+    Username,
+    FollowerCount,
+    FollowingCount,
+    LikesCount,
+    VideoCount,
+}
+
+impl OpenUserField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(OpenUserField::OpenId);
+        set.insert(OpenUserField::UnionId);
+        set.insert(OpenUserField::AvatarUrl);
+        set.insert(OpenUserField::AvatarUrl100);
+        set.insert(OpenUserField::AvatarLargeUrl);
+        set.insert(OpenUserField::DisplayName);
+        set.insert(OpenUserField::BioDescription);
+        set.insert(OpenUserField::ProfileDeepLink);
+        set.insert(OpenUserField::IsVerified);
+        set.insert(OpenUserField::Username);
+        set.insert(OpenUserField::FollowerCount);
+        set.insert(OpenUserField::FollowingCount);
+        set.insert(OpenUserField::LikesCount);
+        set.insert(OpenUserField::VideoCount);
+        set
+    }
+}
+
+// フィールドごとに必要なスコープが異なる
+impl OpenUserField {
+    pub fn scope(&self) -> TiktokScope {
+        match self {
+            Self::OpenId
+            | Self::UnionId
+            | Self::AvatarUrl
+            | Self::AvatarUrl100
+            | Self::AvatarLargeUrl
+            | Self::DisplayName => TiktokScope::UserInfoBasic,
+            Self::BioDescription | Self::ProfileDeepLink | Self::IsVerified | Self::Username => {
+                TiktokScope::UserInfoProfile
+            }
+            Self::FollowerCount | Self::FollowingCount | Self::LikesCount | Self::VideoCount => {
+                TiktokScope::UserInfoStats
+            }
+        }
+    }
+
+    // 許可されたスコープで取得できるフィールドだけを返す
+    pub fn allowed(scopes: &[TiktokScope]) -> HashSet<Self> {
+        Self::all()
+            .into_iter()
+            .filter(|it| scopes.contains(&it.scope()))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for OpenUserField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::OpenId => write!(f, "open_id"),
+            Self::UnionId => write!(f, "union_id"),
+            Self::AvatarUrl => write!(f, "avatar_url"),
+            Self::AvatarUrl100 => write!(f, "avatar_url_100"),
+            Self::AvatarLargeUrl => write!(f, "avatar_large_url"),
+            Self::DisplayName => write!(f, "display_name"),
+            Self::BioDescription => write!(f, "bio_description"),
+            Self::ProfileDeepLink => write!(f, "profile_deep_link"),
+            Self::IsVerified => write!(f, "is_verified"),
+            Self::Username => write!(f, "username"),
+            Self::FollowerCount => write!(f, "follower_count"),
+            Self::FollowingCount => write!(f, "following_count"),
+            Self::LikesCount => write!(f, "likes_count"),
+            Self::VideoCount => write!(f, "video_count"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_open_user_field_allowed -- --nocapture --test-threads=1
+    #[test]
+    fn test_open_user_field_allowed() {
+        let fields = OpenUserField::allowed(&[TiktokScope::UserInfoBasic]);
+        assert_eq!(fields.len(), 6);
+        assert!(fields.contains(&OpenUserField::OpenId));
+        assert!(!fields.contains(&OpenUserField::FollowerCount));
+
+        let fields = OpenUserField::allowed(&[
+            TiktokScope::UserInfoBasic,
+            TiktokScope::UserInfoProfile,
+            TiktokScope::UserInfoStats,
+        ]);
+        assert_eq!(fields, OpenUserField::all());
+    }
+}
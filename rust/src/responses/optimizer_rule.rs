@@ -0,0 +1,664 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OptimizerRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<RuleStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apply_objects: Option<Vec<ApplyObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<Condition>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<Action>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_exec_info: Option<RuleExecInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification: Option<Notification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl OptimizerRule {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .apply_objects
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .conditions
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .actions
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .rule_exec_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .notification
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("OptimizerRule {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum RuleStatus {
+    #[serde(rename = "ON")]
+    #[default]
+    On,
+    #[serde(rename = "OFF")]
+    Off,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for RuleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::On => write!(f, "ON"),
+            Self::Off => write!(f, "OFF"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum OptimizerRuleField {
+    RuleId,
+    Name,
+    Status,
+    ApplyObjects,
+    Conditions,
+    Actions,
+    RuleExecInfo,
+    Notification,
+    CreateTime,
+    UpdateTime,
+}
+
+impl OptimizerRuleField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(OptimizerRuleField::RuleId);
+        set.insert(OptimizerRuleField::Name);
+        set.insert(OptimizerRuleField::Status);
+        set.insert(OptimizerRuleField::ApplyObjects);
+        set.insert(OptimizerRuleField::Conditions);
+        set.insert(OptimizerRuleField::Actions);
+        set.insert(OptimizerRuleField::RuleExecInfo);
+        set.insert(OptimizerRuleField::Notification);
+        set.insert(OptimizerRuleField::CreateTime);
+        set.insert(OptimizerRuleField::UpdateTime);
+        set
+    }
+}
+
+impl std::fmt::Display for OptimizerRuleField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::RuleId => write!(f, "rule_id"),
+            Self::Name => write!(f, "name"),
+            Self::Status => write!(f, "status"),
+            Self::ApplyObjects => write!(f, "apply_objects"),
+            Self::Conditions => write!(f, "conditions"),
+            Self::Actions => write!(f, "actions"),
+            Self::RuleExecInfo => write!(f, "rule_exec_info"),
+            Self::Notification => write!(f, "notification"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::UpdateTime => write!(f, "update_time"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ApplyObject {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension: Option<Dimension>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_condition_type: Option<PreConditionType>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ApplyObject {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ApplyObject {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum Dimension {
+    #[serde(rename = "CAMPAIGN")]
+    #[default]
+    Campaign,
+    #[serde(rename = "ADGROUP")]
+    Adgroup,
+    #[serde(rename = "AD")]
+    Ad,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Campaign => write!(f, "CAMPAIGN"),
+            Self::Adgroup => write!(f, "ADGROUP"),
+            Self::Ad => write!(f, "AD"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum PreConditionType {
+    #[serde(rename = "SELECTED")]
+    #[default]
+    Selected,
+    #[serde(rename = "ALL_ACTIVE_CAMPAIGN")]
+    AllActiveCampaign,
+    #[serde(rename = "ALL_ACTIVE_ADGROUP")]
+    AllActiveAdgroup,
+    #[serde(rename = "ALL_ACTIVE_AD")]
+    AllActiveAd,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for PreConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Selected => write!(f, "SELECTED"),
+            Self::AllActiveCampaign => write!(f, "ALL_ACTIVE_CAMPAIGN"),
+            Self::AllActiveAdgroup => write!(f, "ALL_ACTIVE_ADGROUP"),
+            Self::AllActiveAd => write!(f, "ALL_ACTIVE_AD"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Condition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_type: Option<ConditionSubject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range_type: Option<RangeType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_type: Option<MatchType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Condition {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Condition {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ConditionSubject {
+    #[serde(rename = "SPEND")]
+    #[default]
+    Spend,
+    #[serde(rename = "DAILY_BUDGET_SPENDING_RATE")]
+    DailyBudgetSpendingRate,
+    #[serde(rename = "LIFETIME_BUDGET_SPENDING_RATE")]
+    LifetimeBudgetSpendingRate,
+    #[serde(rename = "IMPRESSIONS")]
+    Impressions,
+    #[serde(rename = "CLICKS")]
+    Clicks,
+    #[serde(rename = "CTR")]
+    Ctr,
+    #[serde(rename = "CPC")]
+    Cpc,
+    #[serde(rename = "CPM")]
+    Cpm,
+    #[serde(rename = "CONVERSIONS")]
+    Conversions,
+    #[serde(rename = "CPA")]
+    Cpa,
+    #[serde(rename = "CVR")]
+    Cvr,
+    #[serde(rename = "FREQUENCY")]
+    Frequency,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ConditionSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Spend => write!(f, "SPEND"),
+            Self::DailyBudgetSpendingRate => write!(f, "DAILY_BUDGET_SPENDING_RATE"),
+            Self::LifetimeBudgetSpendingRate => write!(f, "LIFETIME_BUDGET_SPENDING_RATE"),
+            Self::Impressions => write!(f, "IMPRESSIONS"),
+            Self::Clicks => write!(f, "CLICKS"),
+            Self::Ctr => write!(f, "CTR"),
+            Self::Cpc => write!(f, "CPC"),
+            Self::Cpm => write!(f, "CPM"),
+            Self::Conversions => write!(f, "CONVERSIONS"),
+            Self::Cpa => write!(f, "CPA"),
+            Self::Cvr => write!(f, "CVR"),
+            Self::Frequency => write!(f, "FREQUENCY"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum RangeType {
+    #[serde(rename = "TODAY")]
+    #[default]
+    Today,
+    #[serde(rename = "YESTERDAY")]
+    Yesterday,
+    #[serde(rename = "LAST_THREE_DAYS")]
+    LastThreeDays,
+    #[serde(rename = "LAST_SEVEN_DAYS")]
+    LastSevenDays,
+    #[serde(rename = "LAST_FOURTEEN_DAYS")]
+    LastFourteenDays,
+    #[serde(rename = "LAST_THIRTY_DAYS")]
+    LastThirtyDays,
+    #[serde(rename = "LIFETIME")]
+    Lifetime,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for RangeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Today => write!(f, "TODAY"),
+            Self::Yesterday => write!(f, "YESTERDAY"),
+            Self::LastThreeDays => write!(f, "LAST_THREE_DAYS"),
+            Self::LastSevenDays => write!(f, "LAST_SEVEN_DAYS"),
+            Self::LastFourteenDays => write!(f, "LAST_FOURTEEN_DAYS"),
+            Self::LastThirtyDays => write!(f, "LAST_THIRTY_DAYS"),
+            Self::Lifetime => write!(f, "LIFETIME"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum MatchType {
+    #[serde(rename = "GT")]
+    #[default]
+    Gt,
+    #[serde(rename = "GTE")]
+    Gte,
+    #[serde(rename = "LT")]
+    Lt,
+    #[serde(rename = "LTE")]
+    Lte,
+    #[serde(rename = "EQ")]
+    Eq,
+    #[serde(rename = "BETWEEN")]
+    Between,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for MatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Gt => write!(f, "GT"),
+            Self::Gte => write!(f, "GTE"),
+            Self::Lt => write!(f, "LT"),
+            Self::Lte => write!(f, "LTE"),
+            Self::Eq => write!(f, "EQ"),
+            Self::Between => write!(f, "BETWEEN"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Action {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_type: Option<ActionSubject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<ActionType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<ValueType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_info: Option<FrequencyInfo>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Action {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .frequency_info
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Action {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ActionSubject {
+    #[serde(rename = "TURN_OFF")]
+    #[default]
+    TurnOff,
+    #[serde(rename = "TURN_ON")]
+    TurnOn,
+    #[serde(rename = "BUDGET")]
+    Budget,
+    #[serde(rename = "BID")]
+    Bid,
+    #[serde(rename = "MESSAGE")]
+    Message,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ActionSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::TurnOff => write!(f, "TURN_OFF"),
+            Self::TurnOn => write!(f, "TURN_ON"),
+            Self::Budget => write!(f, "BUDGET"),
+            Self::Bid => write!(f, "BID"),
+            Self::Message => write!(f, "MESSAGE"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ActionType {
+    #[serde(rename = "INCREASE")]
+    #[default]
+    Increase,
+    #[serde(rename = "DECREASE")]
+    Decrease,
+    #[serde(rename = "ADJUST_TO")]
+    AdjustTo,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ActionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Increase => write!(f, "INCREASE"),
+            Self::Decrease => write!(f, "DECREASE"),
+            Self::AdjustTo => write!(f, "ADJUST_TO"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ValueType {
+    #[serde(rename = "EXACT")]
+    #[default]
+    Exact,
+    #[serde(rename = "PERCENT")]
+    Percent,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Exact => write!(f, "EXACT"),
+            Self::Percent => write!(f, "PERCENT"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FrequencyInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<FrequencyType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_frequency_type: Option<CustomFrequencyType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl FrequencyInfo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("FrequencyInfo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum FrequencyType {
+    #[serde(rename = "ONLY_ONCE")]
+    #[default]
+    OnlyOnce,
+    #[serde(rename = "EVERY_RUN")]
+    EveryRun,
+    #[serde(rename = "CUSTOM")]
+    Custom,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for FrequencyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::OnlyOnce => write!(f, "ONLY_ONCE"),
+            Self::EveryRun => write!(f, "EVERY_RUN"),
+            Self::Custom => write!(f, "CUSTOM"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum CustomFrequencyType {
+    #[serde(rename = "N_MINUTES_ONCE")]
+    #[default]
+    NMinutesOnce,
+    #[serde(rename = "N_HOURS_ONCE")]
+    NHoursOnce,
+    #[serde(rename = "N_DAYS_ONCE")]
+    NDaysOnce,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for CustomFrequencyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NMinutesOnce => write!(f, "N_MINUTES_ONCE"),
+            Self::NHoursOnce => write!(f, "N_HOURS_ONCE"),
+            Self::NDaysOnce => write!(f, "N_DAYS_ONCE"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RuleExecInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec_time_type: Option<ExecTimeType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl RuleExecInfo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("RuleExecInfo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ExecTimeType {
+    #[serde(rename = "PER_HALF_HOUR")]
+    #[default]
+    PerHalfHour,
+    #[serde(rename = "PER_ONE_HOUR")]
+    PerOneHour,
+    #[serde(rename = "PER_ONE_DAY")]
+    PerOneDay,
+    #[serde(rename = "SPECIFIC_TIME")]
+    SpecificTime,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ExecTimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PerHalfHour => write!(f, "PER_HALF_HOUR"),
+            Self::PerOneHour => write!(f, "PER_ONE_HOUR"),
+            Self::PerOneDay => write!(f, "PER_ONE_DAY"),
+            Self::SpecificTime => write!(f, "SPECIFIC_TIME"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Notification {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_type: Option<NotificationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_setting: Option<EmailSetting>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Notification {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .email_setting
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("Notification {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum NotificationType {
+    #[serde(rename = "NOT_NOTIFY")]
+    #[default]
+    NotNotify,
+    #[serde(rename = "ANY_CHANGES")]
+    AnyChanges,
+    #[serde(rename = "TASK_FINISH")]
+    TaskFinish,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NotNotify => write!(f, "NOT_NOTIFY"),
+            Self::AnyChanges => write!(f, "ANY_CHANGES"),
+            Self::TaskFinish => write!(f, "TASK_FINISH"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EmailSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_period: Option<NotificationPeriod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_exec_time: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl EmailSetting {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("EmailSetting {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum NotificationPeriod {
+    #[serde(rename = "EVERY_TIME")]
+    #[default]
+    EveryTime,
+    #[serde(rename = "DAILY")]
+    Daily,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for NotificationPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::EveryTime => write!(f, "EVERY_TIME"),
+            Self::Daily => write!(f, "DAILY"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
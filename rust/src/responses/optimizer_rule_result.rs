@@ -0,0 +1,125 @@
+use crate::responses::optimizer_rule::ActionSubject;
+use crate::responses::optimizer_rule::Dimension;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OptimizerRuleResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_type: Option<ResultType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_details: Option<Vec<ResultDetail>>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl OptimizerRuleResult {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .result_details
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true);
+        if !res {
+            println!("OptimizerRuleResult {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub enum ResultType {
+    #[serde(rename = "SUCCESS")]
+    #[default]
+    Success,
+    #[serde(rename = "FAILED")]
+    Failed,
+    #[serde(rename = "PARTIAL_SUCCESS")]
+    PartialSuccess,
+    #[serde(rename = "NO_OBJECT_MATCHED")]
+    NoObjectMatched,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for ResultType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Success => write!(f, "SUCCESS"),
+            Self::Failed => write!(f, "FAILED"),
+            Self::PartialSuccess => write!(f, "PARTIAL_SUCCESS"),
+            Self::NoObjectMatched => write!(f, "NO_OBJECT_MATCHED"),
+            Self::Unknown(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum OptimizerRuleResultField {
+    ResultId,
+    RuleId,
+    ExecTime,
+    ResultType,
+    ResultDetails,
+}
+
+impl OptimizerRuleResultField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(OptimizerRuleResultField::ResultId);
+        set.insert(OptimizerRuleResultField::RuleId);
+        set.insert(OptimizerRuleResultField::ExecTime);
+        set.insert(OptimizerRuleResultField::ResultType);
+        set.insert(OptimizerRuleResultField::ResultDetails);
+        set
+    }
+}
+
+impl std::fmt::Display for OptimizerRuleResultField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ResultId => write!(f, "result_id"),
+            Self::RuleId => write!(f, "rule_id"),
+            Self::ExecTime => write!(f, "exec_time"),
+            Self::ResultType => write!(f, "result_type"),
+            Self::ResultDetails => write!(f, "result_details"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResultDetail {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension: Option<Dimension>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_subject: Option<ActionSubject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_reason: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ResultDetail {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ResultDetail {:?}", self.extra);
+        }
+        res
+    }
+}
@@ -0,0 +1,57 @@
+use crate::responses::ad_group::OperatingSystem;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OsVersion {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_type: Option<OperatingSystem>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl OsVersion {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("OsVersion {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum OsVersionField {
+    OsId,
+    Name,
+    Version,
+    OsType,
+}
+
+impl OsVersionField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(OsVersionField::OsId);
+        set.insert(OsVersionField::Name);
+        set.insert(OsVersionField::Version);
+        set.insert(OsVersionField::OsType);
+        set
+    }
+}
+
+impl std::fmt::Display for OsVersionField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::OsId => write!(f, "os_id"),
+            Self::Name => write!(f, "name"),
+            Self::Version => write!(f, "version"),
+            Self::OsType => write!(f, "os_type"),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PageInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_number: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_page: Option<i64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl PageInfo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("PageInfo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum PageInfoField {
+    Page,
+    PageSize,
+    TotalNumber,
+    TotalPage,
+}
+
+impl PageInfoField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(PageInfoField::Page);
+        set.insert(PageInfoField::PageSize);
+        set.insert(PageInfoField::TotalNumber);
+        set.insert(PageInfoField::TotalPage);
+        set
+    }
+}
+
+impl std::fmt::Display for PageInfoField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Page => write!(f, "page"),
+            Self::PageSize => write!(f, "page_size"),
+            Self::TotalNumber => write!(f, "total_number"),
+            Self::TotalPage => write!(f, "total_page"),
+        }
+    }
+}
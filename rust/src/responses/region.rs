@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Region {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_level_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_below_18: Option<bool>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Region {
+    // US-CAのような地域コードは先頭の国コードで変換する
+    pub fn country_code(&self) -> Option<isocountry::CountryCode> {
+        let code = self.region_code.as_deref()?.split('-').next()?;
+        isocountry::CountryCode::for_alpha2_caseless(code).ok()
+    }
+
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("Region {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum RegionField {
+    LocationId,
+    Name,
+    RegionCode,
+    Level,
+    ParentId,
+    NextLevelIds,
+    SupportBelow18,
+}
+
+impl RegionField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(RegionField::LocationId);
+        set.insert(RegionField::Name);
+        set.insert(RegionField::RegionCode);
+        set.insert(RegionField::Level);
+        set.insert(RegionField::ParentId);
+        set.insert(RegionField::NextLevelIds);
+        set.insert(RegionField::SupportBelow18);
+        set
+    }
+}
+
+impl std::fmt::Display for RegionField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::LocationId => write!(f, "location_id"),
+            Self::Name => write!(f, "name"),
+            Self::RegionCode => write!(f, "region_code"),
+            Self::Level => write!(f, "level"),
+            Self::ParentId => write!(f, "parent_id"),
+            Self::NextLevelIds => write!(f, "next_level_ids"),
+            Self::SupportBelow18 => write!(f, "support_below_18"),
+        }
+    }
+}
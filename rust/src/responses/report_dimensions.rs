@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ReportDimensions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advertiser_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adgroup_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stat_time_day: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stat_time_hour: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<isocountry::CountryCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ac: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placement: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ReportDimensions {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ReportDimensions {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum ReportDimensionsField {
+    AdvertiserId,
+    CampaignId,
+    AdgroupId,
+    AdId,
+    StatTimeDay,
+    StatTimeHour,
+    CountryCode,
+    Gender,
+    Age,
+    Platform,
+    Ac,
+    Language,
+    Placement,
+}
+
+impl ReportDimensionsField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(ReportDimensionsField::AdvertiserId);
+        set.insert(ReportDimensionsField::CampaignId);
+        set.insert(ReportDimensionsField::AdgroupId);
+        set.insert(ReportDimensionsField::AdId);
+        set.insert(ReportDimensionsField::StatTimeDay);
+        set.insert(ReportDimensionsField::StatTimeHour);
+        set.insert(ReportDimensionsField::CountryCode);
+        set.insert(ReportDimensionsField::Gender);
+        set.insert(ReportDimensionsField::Age);
+        set.insert(ReportDimensionsField::Platform);
+        set.insert(ReportDimensionsField::Ac);
+        set.insert(ReportDimensionsField::Language);
+        set.insert(ReportDimensionsField::Placement);
+        set
+    }
+}
+
+impl std::fmt::Display for ReportDimensionsField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AdvertiserId => write!(f, "advertiser_id"),
+            Self::CampaignId => write!(f, "campaign_id"),
+            Self::AdgroupId => write!(f, "adgroup_id"),
+            Self::AdId => write!(f, "ad_id"),
+            Self::StatTimeDay => write!(f, "stat_time_day"),
+            Self::StatTimeHour => write!(f, "stat_time_hour"),
+            Self::CountryCode => write!(f, "country_code"),
+            Self::Gender => write!(f, "gender"),
+            Self::Age => write!(f, "age"),
+            Self::Platform => write!(f, "platform"),
+            Self::Ac => write!(f, "ac"),
+            Self::Language => write!(f, "language"),
+            Self::Placement => write!(f, "placement"),
+        }
+    }
+}
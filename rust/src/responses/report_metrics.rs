@@ -0,0 +1,204 @@
+use crate::responses::string_number;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ReportMetrics {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub spend: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub impressions: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub clicks: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub ctr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub cpc: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub cpm: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub reach: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub frequency: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub conversion: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub cost_per_conversion: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub conversion_rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub real_time_conversion: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub result: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub cost_per_result: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub video_play_actions: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub video_watched_2s: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub video_watched_6s: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub video_views_p25: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub video_views_p50: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub video_views_p75: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub video_views_p100: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_f64")]
+    pub average_video_play: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub likes: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub comments: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub shares: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub follows: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "string_number::option_i64")]
+    pub profile_visits: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ReportMetrics {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ReportMetrics {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum ReportMetricsField {
+    Spend,
+    Impressions,
+    Clicks,
+    Ctr,
+    Cpc,
+    Cpm,
+    Reach,
+    Frequency,
+    Conversion,
+    CostPerConversion,
+    ConversionRate,
+    RealTimeConversion,
+    Result,
+    CostPerResult,
+    VideoPlayActions,
+    VideoWatched2s,
+    VideoWatched6s,
+    VideoViewsP25,
+    VideoViewsP50,
+    VideoViewsP75,
+    VideoViewsP100,
+    AverageVideoPlay,
+    Likes,
+    Comments,
+    Shares,
+    Follows,
+    ProfileVisits,
+    Currency,
+}
+
+impl ReportMetricsField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(ReportMetricsField::Spend);
+        set.insert(ReportMetricsField::Impressions);
+        set.insert(ReportMetricsField::Clicks);
+        set.insert(ReportMetricsField::Ctr);
+        set.insert(ReportMetricsField::Cpc);
+        set.insert(ReportMetricsField::Cpm);
+        set.insert(ReportMetricsField::Reach);
+        set.insert(ReportMetricsField::Frequency);
+        set.insert(ReportMetricsField::Conversion);
+        set.insert(ReportMetricsField::CostPerConversion);
+        set.insert(ReportMetricsField::ConversionRate);
+        set.insert(ReportMetricsField::RealTimeConversion);
+        set.insert(ReportMetricsField::Result);
+        set.insert(ReportMetricsField::CostPerResult);
+        set.insert(ReportMetricsField::VideoPlayActions);
+        set.insert(ReportMetricsField::VideoWatched2s);
+        set.insert(ReportMetricsField::VideoWatched6s);
+        set.insert(ReportMetricsField::VideoViewsP25);
+        set.insert(ReportMetricsField::VideoViewsP50);
+        set.insert(ReportMetricsField::VideoViewsP75);
+        set.insert(ReportMetricsField::VideoViewsP100);
+        set.insert(ReportMetricsField::AverageVideoPlay);
+        set.insert(ReportMetricsField::Likes);
+        set.insert(ReportMetricsField::Comments);
+        set.insert(ReportMetricsField::Shares);
+        set.insert(ReportMetricsField::Follows);
+        set.insert(ReportMetricsField::ProfileVisits);
+        set.insert(ReportMetricsField::Currency);
+        set
+    }
+}
+
+impl std::fmt::Display for ReportMetricsField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Spend => write!(f, "spend"),
+            Self::Impressions => write!(f, "impressions"),
+            Self::Clicks => write!(f, "clicks"),
+            Self::Ctr => write!(f, "ctr"),
+            Self::Cpc => write!(f, "cpc"),
+            Self::Cpm => write!(f, "cpm"),
+            Self::Reach => write!(f, "reach"),
+            Self::Frequency => write!(f, "frequency"),
+            Self::Conversion => write!(f, "conversion"),
+            Self::CostPerConversion => write!(f, "cost_per_conversion"),
+            Self::ConversionRate => write!(f, "conversion_rate"),
+            Self::RealTimeConversion => write!(f, "real_time_conversion"),
+            Self::Result => write!(f, "result"),
+            Self::CostPerResult => write!(f, "cost_per_result"),
+            Self::VideoPlayActions => write!(f, "video_play_actions"),
+            Self::VideoWatched2s => write!(f, "video_watched_2s"),
+            Self::VideoWatched6s => write!(f, "video_watched_6s"),
+            Self::VideoViewsP25 => write!(f, "video_views_p25"),
+            Self::VideoViewsP50 => write!(f, "video_views_p50"),
+            Self::VideoViewsP75 => write!(f, "video_views_p75"),
+            Self::VideoViewsP100 => write!(f, "video_views_p100"),
+            Self::AverageVideoPlay => write!(f, "average_video_play"),
+            Self::Likes => write!(f, "likes"),
+            Self::Comments => write!(f, "comments"),
+            Self::Shares => write!(f, "shares"),
+            Self::Follows => write!(f, "follows"),
+            Self::ProfileVisits => write!(f, "profile_visits"),
+            Self::Currency => write!(f, "currency"),
+        }
+    }
+}
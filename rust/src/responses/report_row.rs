@@ -0,0 +1,57 @@
+use crate::responses::{report_dimensions::ReportDimensions, report_metrics::ReportMetrics};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ReportRow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<ReportDimensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<ReportMetrics>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ReportRow {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .dimensions
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true)
+            && self
+                .metrics
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("ReportRow {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum ReportRowField {
+    Dimensions,
+    Metrics,
+}
+
+impl ReportRowField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(ReportRowField::Dimensions);
+        set.insert(ReportRowField::Metrics);
+        set
+    }
+}
+
+impl std::fmt::Display for ReportRowField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Dimensions => write!(f, "dimensions"),
+            Self::Metrics => write!(f, "metrics"),
+        }
+    }
+}
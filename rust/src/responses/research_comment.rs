@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResearchComment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<i64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ResearchComment {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ResearchComment {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum ResearchCommentField {
+    Id,
+    VideoId,
+    Text,
+    LikeCount,
+    ReplyCount,
+    ParentCommentId,
+    CreateTime,
+}
+
+impl ResearchCommentField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(ResearchCommentField::Id);
+        set.insert(ResearchCommentField::VideoId);
+        set.insert(ResearchCommentField::Text);
+        set.insert(ResearchCommentField::LikeCount);
+        set.insert(ResearchCommentField::ReplyCount);
+        set.insert(ResearchCommentField::ParentCommentId);
+        set.insert(ResearchCommentField::CreateTime);
+        set
+    }
+}
+
+impl std::fmt::Display for ResearchCommentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Id => write!(f, "id"),
+            Self::VideoId => write!(f, "video_id"),
+            Self::Text => write!(f, "text"),
+            Self::LikeCount => write!(f, "like_count"),
+            Self::ReplyCount => write!(f, "reply_count"),
+            Self::ParentCommentId => write!(f, "parent_comment_id"),
+            Self::CreateTime => write!(f, "create_time"),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResearchFollower {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ResearchFollower {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ResearchFollower {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum ResearchFollowerField {
+    DisplayName,
+    Username,
+}
+
+impl ResearchFollowerField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(ResearchFollowerField::DisplayName);
+        set.insert(ResearchFollowerField::Username);
+        set
+    }
+}
+
+impl std::fmt::Display for ResearchFollowerField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DisplayName => write!(f, "display_name"),
+            Self::Username => write!(f, "username"),
+        }
+    }
+}
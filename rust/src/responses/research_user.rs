@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResearchUser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follower_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub following_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub likes_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ResearchUser {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("ResearchUser {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum ResearchUserField {
+    DisplayName,
+    BioDescription,
+    AvatarUrl,
+    IsVerified,
+    FollowerCount,
+    FollowingCount,
+    LikesCount,
+    VideoCount,
+    BioUrl,
+}
+
+impl ResearchUserField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(ResearchUserField::DisplayName);
+        set.insert(ResearchUserField::BioDescription);
+        set.insert(ResearchUserField::AvatarUrl);
+        set.insert(ResearchUserField::IsVerified);
+        set.insert(ResearchUserField::FollowerCount);
+        set.insert(ResearchUserField::FollowingCount);
+        set.insert(ResearchUserField::LikesCount);
+        set.insert(ResearchUserField::VideoCount);
+        set.insert(ResearchUserField::BioUrl);
+        set
+    }
+}
+
+impl std::fmt::Display for ResearchUserField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DisplayName => write!(f, "display_name"),
+            Self::BioDescription => write!(f, "bio_description"),
+            Self::AvatarUrl => write!(f, "avatar_url"),
+            Self::IsVerified => write!(f, "is_verified"),
+            Self::FollowerCount => write!(f, "follower_count"),
+            Self::FollowingCount => write!(f, "following_count"),
+            Self::LikesCount => write!(f, "likes_count"),
+            Self::VideoCount => write!(f, "video_count"),
+            Self::BioUrl => write!(f, "bio_url"),
+        }
+    }
+}
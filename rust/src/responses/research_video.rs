@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ResearchVideo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favorites_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub music_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashtag_names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashtag_info_list: Option<Vec<HashtagInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playlist_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_to_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_stem_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_mention_list: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_label: Option<VideoLabel>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl ResearchVideo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty()
+            && self
+                .hashtag_info_list
+                .as_ref()
+                .map(|it| it.iter().all(|item| item.is_empty_extra()))
+                .unwrap_or(true)
+            && self
+                .video_label
+                .as_ref()
+                .map(|it| it.is_empty_extra())
+                .unwrap_or(true);
+        if !res {
+            println!("ResearchVideo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum ResearchVideoField {
+    Id,
+    VideoDescription,
+    CreateTime,
+    RegionCode,
+    ShareCount,
+    ViewCount,
+    LikeCount,
+    CommentCount,
+    FavoritesCount,
+    MusicId,
+    HashtagNames,
+    HashtagInfoList,
+    Username,
+    EffectIds,
+    PlaylistId,
+    VoiceToText,
+    IsStemVerified,
+    VideoDuration,
+    VideoMentionList,
+    VideoLabel,
+}
+
+impl ResearchVideoField {
+    pub fn all() -> HashSet<Self> {
+        let mut set = HashSet::new();
+        set.insert(ResearchVideoField::Id);
+        set.insert(ResearchVideoField::VideoDescription);
+        set.insert(ResearchVideoField::CreateTime);
+        set.insert(ResearchVideoField::RegionCode);
+        set.insert(ResearchVideoField::ShareCount);
+        set.insert(ResearchVideoField::ViewCount);
+        set.insert(ResearchVideoField::LikeCount);
+        set.insert(ResearchVideoField::CommentCount);
+        set.insert(ResearchVideoField::FavoritesCount);
+        set.insert(ResearchVideoField::MusicId);
+        set.insert(ResearchVideoField::HashtagNames);
+        set.insert(ResearchVideoField::HashtagInfoList);
+        set.insert(ResearchVideoField::Username);
+        set.insert(ResearchVideoField::EffectIds);
+        set.insert(ResearchVideoField::PlaylistId);
+        set.insert(ResearchVideoField::VoiceToText);
+        set.insert(ResearchVideoField::IsStemVerified);
+        set.insert(ResearchVideoField::VideoDuration);
+        set.insert(ResearchVideoField::VideoMentionList);
+        set.insert(ResearchVideoField::VideoLabel);
+        set
+    }
+}
+
+impl std::fmt::Display for ResearchVideoField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Id => write!(f, "id"),
+            Self::VideoDescription => write!(f, "video_description"),
+            Self::CreateTime => write!(f, "create_time"),
+            Self::RegionCode => write!(f, "region_code"),
+            Self::ShareCount => write!(f, "share_count"),
+            Self::ViewCount => write!(f, "view_count"),
+            Self::LikeCount => write!(f, "like_count"),
+            Self::CommentCount => write!(f, "comment_count"),
+            Self::FavoritesCount => write!(f, "favorites_count"),
+            Self::MusicId => write!(f, "music_id"),
+            Self::HashtagNames => write!(f, "hashtag_names"),
+            Self::HashtagInfoList => write!(f, "hashtag_info_list"),
+            Self::Username => write!(f, "username"),
+            Self::EffectIds => write!(f, "effect_ids"),
+            Self::PlaylistId => write!(f, "playlist_id"),
+            Self::VoiceToText => write!(f, "voice_to_text"),
+            Self::IsStemVerified => write!(f, "is_stem_verified"),
+            Self::VideoDuration => write!(f, "video_duration"),
+            Self::VideoMentionList => write!(f, "video_mention_list"),
+            Self::VideoLabel => write!(f, "video_label"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct HashtagInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashtag_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashtag_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashtag_description: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl HashtagInfo {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("HashtagInfo {:?}", self.extra);
+        }
+        res
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct VideoLabel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl VideoLabel {
+    pub fn is_empty_extra(&self) -> bool {
+        let res = self.extra.is_empty();
+        if !res {
+            println!("VideoLabel {:?}", self.extra);
+        }
+        res
+    }
+}
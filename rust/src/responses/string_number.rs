@@ -0,0 +1,85 @@
+use serde::{Deserialize, Deserializer, de::Error};
+
+// レポートの数値は文字列で返ってくる。"-"や空文字の場合もある。
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber {
+    String(String),
+    Number(serde_json::Number),
+}
+
+pub(crate) fn option_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<StringOrNumber>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(StringOrNumber::Number(value)) => Ok(value.as_f64()),
+        Some(StringOrNumber::String(value)) => match value.trim() {
+            "" | "-" => Ok(None),
+            value => value.parse::<f64>().map(Some).map_err(D::Error::custom),
+        },
+    }
+}
+
+// 小数部がある値は切り捨てずにエラーにする
+pub(crate) fn option_i64<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<StringOrNumber>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(StringOrNumber::Number(value)) => match value.as_i64() {
+            Some(value) => Ok(Some(value)),
+            None => integral(value.as_f64(), &value.to_string()).map_err(D::Error::custom),
+        },
+        Some(StringOrNumber::String(value)) => match value.trim() {
+            "" | "-" => Ok(None),
+            value => match value.parse::<i64>() {
+                Ok(value) => Ok(Some(value)),
+                Err(_) => integral(value.parse::<f64>().ok(), value).map_err(D::Error::custom),
+            },
+        },
+    }
+}
+
+// 1000.0 や 1e3 のような整数値だけを受け付ける
+fn integral(value: Option<f64>, raw: &str) -> Result<Option<i64>, String> {
+    match value {
+        Some(value)
+            if value.fract() == 0.0 && value >= i64::MIN as f64 && value < i64::MAX as f64 =>
+        {
+            Ok(Some(value as i64))
+        }
+        _ => Err(format!("invalid integer: {}", raw)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::responses::report_metrics::ReportMetrics;
+
+    // cargo test test_string_number_report_metrics -- --nocapture --test-threads=1
+    #[test]
+    fn test_string_number_report_metrics() -> anyhow::Result<()> {
+        let metrics = serde_json::from_str::<ReportMetrics>(
+            r#"{"spend":"12.50","impressions":"1000","clicks":7,"ctr":"-","cpc":"","currency":"USD"}"#,
+        )?;
+        assert_eq!(metrics.spend, Some(12.5));
+        assert_eq!(metrics.impressions, Some(1000));
+        assert_eq!(metrics.clicks, Some(7));
+        assert_eq!(metrics.ctr, None);
+        assert_eq!(metrics.cpc, None);
+        assert_eq!(metrics.cpm, None);
+        assert!(metrics.is_empty_extra());
+        assert!(serde_json::from_str::<ReportMetrics>(r#"{"spend":"abc"}"#).is_err());
+
+        let metrics =
+            serde_json::from_str::<ReportMetrics>(r#"{"impressions":"1000.0","clicks":7.0}"#)?;
+        assert_eq!(metrics.impressions, Some(1000));
+        assert_eq!(metrics.clicks, Some(7));
+        assert!(serde_json::from_str::<ReportMetrics>(r#"{"impressions":"12.7"}"#).is_err());
+        assert!(serde_json::from_str::<ReportMetrics>(r#"{"clicks":12.7}"#).is_err());
+        Ok(())
+    }
+}
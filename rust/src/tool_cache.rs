@@ -0,0 +1,407 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    apis::{
+        get_tool_device_model, get_tool_interest_category, get_tool_language, get_tool_os_version,
+        get_tool_region,
+    },
+    error::Error,
+    options::TiktokOptions,
+    responses::{
+        ad_group::{OperatingSystem, Placement},
+        campaign::ObjectiveType,
+        device_model::DeviceModel,
+        interest_category::InterestCategory,
+        language::Language,
+        os_version::OsVersion,
+        region::Region,
+    },
+};
+use isocountry::CountryCode;
+
+type Cache<T> = Mutex<HashMap<String, (Instant, Vec<T>)>>;
+
+// toolのAPIの結果をttlの間キャッシュする
+#[derive(Debug, Default)]
+pub struct ToolCache {
+    options: Option<TiktokOptions>,
+    advertiser_id: String,
+    ttl: Duration,
+    placements: Option<Vec<Placement>>,
+    objective_type: Option<ObjectiveType>,
+    language: Option<String>,
+    regions: Cache<Region>,
+    interest_categories: Cache<InterestCategory>,
+    languages: Cache<Language>,
+    os_versions: Cache<OsVersion>,
+    device_models: Cache<DeviceModel>,
+}
+
+impl ToolCache {
+    pub fn new(advertiser_id: &str, options: Option<TiktokOptions>) -> Self {
+        Self {
+            options,
+            advertiser_id: advertiser_id.to_owned(),
+            ttl: Duration::from_secs(24 * 60 * 60),
+            ..Default::default()
+        }
+    }
+
+    pub fn ttl(mut self, value: Duration) -> Self {
+        self.ttl = value;
+        self
+    }
+
+    pub fn placements(mut self, value: Vec<Placement>) -> Self {
+        self.placements = Some(value);
+        self
+    }
+
+    pub fn objective_type(mut self, value: ObjectiveType) -> Self {
+        self.objective_type = Some(value);
+        self
+    }
+
+    pub fn language(mut self, value: &str) -> Self {
+        self.language = Some(value.to_owned());
+        self
+    }
+
+    pub fn clear(&self) {
+        clear_cache(&self.regions);
+        clear_cache(&self.interest_categories);
+        clear_cache(&self.languages);
+        clear_cache(&self.os_versions);
+        clear_cache(&self.device_models);
+    }
+
+    pub async fn regions(&self, bearer_code: &str) -> Result<Vec<Region>, Error> {
+        if let Some(values) = get_cache(&self.regions, "", self.ttl) {
+            return Ok(values);
+        }
+        let mut api = get_tool_region::Api::new(&self.advertiser_id, self.options.clone());
+        if let Some(placements) = self.placements.clone() {
+            api = api.placements(placements);
+        }
+        if let Some(objective_type) = self.objective_type.clone() {
+            api = api.objective_type(objective_type);
+        }
+        let res = api.execute(bearer_code).await?;
+        if res.body.code != 0 {
+            return Err(Error::Api(res.body.code, res.body.message, res.status_code));
+        }
+        let values = res
+            .body
+            .data
+            .and_then(|it| it.region_list)
+            .unwrap_or_default();
+        set_cache(&self.regions, "", values.clone());
+        Ok(values)
+    }
+
+    pub async fn search_regions(
+        &self,
+        bearer_code: &str,
+        name: &str,
+    ) -> Result<Vec<Region>, Error> {
+        let values = self.regions(bearer_code).await?;
+        Ok(search(values, name, |it| it.name.as_deref()))
+    }
+
+    pub async fn country_region(
+        &self,
+        bearer_code: &str,
+        country_code: CountryCode,
+    ) -> Result<Option<Region>, Error> {
+        let values = self.regions(bearer_code).await?;
+        Ok(values.into_iter().find(|it| {
+            it.level.as_deref() == Some("COUNTRY") && it.country_code() == Some(country_code)
+        }))
+    }
+
+    pub async fn country_location_ids(
+        &self,
+        bearer_code: &str,
+        countries: &[CountryCode],
+    ) -> Result<Vec<String>, Error> {
+        let values = self.regions(bearer_code).await?;
+        country_location_ids(&values, countries)
+    }
+
+    // 広告グループの location_ids のうち国単位のものを国コードで返す
+    pub async fn location_countries(
+        &self,
+        bearer_code: &str,
+        location_ids: &[String],
+    ) -> Result<Vec<CountryCode>, Error> {
+        let values = self.regions(bearer_code).await?;
+        Ok(location_countries(&values, location_ids))
+    }
+
+    pub async fn interest_categories(
+        &self,
+        bearer_code: &str,
+    ) -> Result<Vec<InterestCategory>, Error> {
+        if let Some(values) = get_cache(&self.interest_categories, "", self.ttl) {
+            return Ok(values);
+        }
+        let mut api =
+            get_tool_interest_category::Api::new(&self.advertiser_id, self.options.clone());
+        if let Some(placements) = self.placements.clone() {
+            api = api.placements(placements);
+        }
+        if let Some(language) = self.language.as_ref() {
+            api = api.language(language);
+        }
+        let res = api.execute(bearer_code).await?;
+        if res.body.code != 0 {
+            return Err(Error::Api(res.body.code, res.body.message, res.status_code));
+        }
+        let values = res
+            .body
+            .data
+            .and_then(|it| it.interest_categories)
+            .unwrap_or_default();
+        set_cache(&self.interest_categories, "", values.clone());
+        Ok(values)
+    }
+
+    pub async fn search_interest_categories(
+        &self,
+        bearer_code: &str,
+        name: &str,
+    ) -> Result<Vec<InterestCategory>, Error> {
+        let values = self.interest_categories(bearer_code).await?;
+        Ok(search(values, name, |it| it.name.as_deref()))
+    }
+
+    pub async fn languages(&self, bearer_code: &str) -> Result<Vec<Language>, Error> {
+        if let Some(values) = get_cache(&self.languages, "", self.ttl) {
+            return Ok(values);
+        }
+        let res = get_tool_language::Api::new(&self.advertiser_id, self.options.clone())
+            .execute(bearer_code)
+            .await?;
+        if res.body.code != 0 {
+            return Err(Error::Api(res.body.code, res.body.message, res.status_code));
+        }
+        let values = res
+            .body
+            .data
+            .and_then(|it| it.languages)
+            .unwrap_or_default();
+        set_cache(&self.languages, "", values.clone());
+        Ok(values)
+    }
+
+    pub async fn search_languages(
+        &self,
+        bearer_code: &str,
+        name: &str,
+    ) -> Result<Vec<Language>, Error> {
+        let values = self.languages(bearer_code).await?;
+        Ok(search(values, name, |it| it.name.as_deref()))
+    }
+
+    pub async fn os_versions(
+        &self,
+        bearer_code: &str,
+        os_type: OperatingSystem,
+    ) -> Result<Vec<OsVersion>, Error> {
+        let key = os_type.to_string();
+        if let Some(values) = get_cache(&self.os_versions, &key, self.ttl) {
+            return Ok(values);
+        }
+        let res = get_tool_os_version::Api::new(&self.advertiser_id, os_type, self.options.clone())
+            .execute(bearer_code)
+            .await?;
+        if res.body.code != 0 {
+            return Err(Error::Api(res.body.code, res.body.message, res.status_code));
+        }
+        let values = res
+            .body
+            .data
+            .and_then(|it| it.os_versions)
+            .unwrap_or_default();
+        set_cache(&self.os_versions, &key, values.clone());
+        Ok(values)
+    }
+
+    pub async fn device_models(&self, bearer_code: &str) -> Result<Vec<DeviceModel>, Error> {
+        if let Some(values) = get_cache(&self.device_models, "", self.ttl) {
+            return Ok(values);
+        }
+        let res = get_tool_device_model::Api::new(&self.advertiser_id, self.options.clone())
+            .execute(bearer_code)
+            .await?;
+        if res.body.code != 0 {
+            return Err(Error::Api(res.body.code, res.body.message, res.status_code));
+        }
+        let values = res
+            .body
+            .data
+            .and_then(|it| it.device_models)
+            .unwrap_or_default();
+        set_cache(&self.device_models, "", values.clone());
+        Ok(values)
+    }
+
+    pub async fn search_device_models(
+        &self,
+        bearer_code: &str,
+        name: &str,
+    ) -> Result<Vec<DeviceModel>, Error> {
+        let values = self.device_models(bearer_code).await?;
+        Ok(search(values, name, |it| it.device_model_name.as_deref()))
+    }
+}
+
+fn get_cache<T: Clone>(cache: &Cache<T>, key: &str, ttl: Duration) -> Option<Vec<T>> {
+    let cache = cache.lock().unwrap();
+    let (fetched_at, values) = cache.get(key)?;
+    if fetched_at.elapsed() > ttl {
+        return None;
+    }
+    Some(values.clone())
+}
+
+fn set_cache<T>(cache: &Cache<T>, key: &str, values: Vec<T>) {
+    cache
+        .lock()
+        .unwrap()
+        .insert(key.to_owned(), (Instant::now(), values));
+}
+
+fn clear_cache<T>(cache: &Cache<T>) {
+    cache.lock().unwrap().clear();
+}
+
+// 名前の部分一致、大文字小文字は区別しない
+fn search<T>(values: Vec<T>, name: &str, f: impl Fn(&T) -> Option<&str>) -> Vec<T> {
+    let name = name.to_lowercase();
+    values
+        .into_iter()
+        .filter(|it| f(it).is_some_and(|value| value.to_lowercase().contains(&name)))
+        .collect()
+}
+
+// 国コードを COUNTRY レベルの location_id に変換する。広告グループの location_ids に使う
+pub fn country_location_ids(
+    regions: &[Region],
+    countries: &[CountryCode],
+) -> Result<Vec<String>, Error> {
+    countries
+        .iter()
+        .map(|country| {
+            regions
+                .iter()
+                .find(|it| {
+                    it.level.as_deref() == Some("COUNTRY") && it.country_code() == Some(*country)
+                })
+                .and_then(|it| it.location_id.clone())
+                .ok_or_else(|| Error::Invalid(format!("country {}", country.alpha2())))
+        })
+        .collect()
+}
+
+// COUNTRY レベルの location_id だけを国コードに戻す
+pub fn location_countries(regions: &[Region], location_ids: &[String]) -> Vec<CountryCode> {
+    location_ids
+        .iter()
+        .filter_map(|id| {
+            regions
+                .iter()
+                .find(|it| {
+                    it.level.as_deref() == Some("COUNTRY") && it.location_id.as_ref() == Some(id)
+                })
+                .and_then(|it| it.country_code())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_tool_cache_search_regions -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_tool_cache_search_regions() -> anyhow::Result<()> {
+        let cache = ToolCache::new("advertiser_id", None);
+        let regions: Vec<Region> = serde_json::from_str(
+            r#"[
+                {"location_id":"6252001","name":"United States","region_code":"US","level":"COUNTRY"},
+                {"location_id":"5332921","name":"California","region_code":"US-CA","level":"PROVINCE","parent_id":"6252001"},
+                {"location_id":"1861060","name":"Japan","region_code":"JP","level":"COUNTRY"}
+            ]"#,
+        )?;
+        set_cache(&cache.regions, "", regions);
+
+        let res = cache.search_regions("", "cali").await?;
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].country_code(), Some(CountryCode::USA));
+
+        let res = cache.country_region("", CountryCode::JPN).await?;
+        assert_eq!(
+            res.and_then(|it| it.location_id).as_deref(),
+            Some("1861060")
+        );
+
+        cache.clear();
+        assert!(get_cache(&cache.regions, "", cache.ttl).is_none());
+        Ok(())
+    }
+
+    // cargo test test_tool_cache_country_location_ids -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_tool_cache_country_location_ids() -> anyhow::Result<()> {
+        use crate::apis::post_adgroup_create::Body;
+        use crate::responses::audience_country::AudienceCountry;
+
+        let cache = ToolCache::new("advertiser_id", None);
+        let regions: Vec<Region> = serde_json::from_str(
+            r#"[
+                {"location_id":"6252001","name":"United States","region_code":"US","level":"COUNTRY"},
+                {"location_id":"5332921","name":"California","region_code":"US-CA","level":"PROVINCE","parent_id":"6252001"},
+                {"location_id":"1861060","name":"Japan","region_code":"JP","level":"COUNTRY"}
+            ]"#,
+        )?;
+        set_cache(&cache.regions, "", regions);
+
+        let mut body = Body {
+            location_ids: vec!["5332921".to_owned()],
+            ..Default::default()
+        };
+        body.location_ids.extend(
+            cache
+                .country_location_ids("", &[CountryCode::JPN, CountryCode::USA])
+                .await?,
+        );
+        assert_eq!(
+            serde_json::to_value(&body)?["location_ids"],
+            serde_json::json!(["5332921", "1861060", "6252001"])
+        );
+        assert!(
+            cache
+                .country_location_ids("", &[CountryCode::FRA])
+                .await
+                .is_err()
+        );
+
+        // audience_country と同じ形式で国コードをやり取りできる
+        let countries = cache.location_countries("", &body.location_ids).await?;
+        assert_eq!(countries, vec![CountryCode::JPN, CountryCode::USA]);
+        let country = AudienceCountry {
+            country: Some(CountryCode::JPN),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&country)?["country"],
+            serde_json::to_value(countries[0])?
+        );
+        Ok(())
+    }
+}
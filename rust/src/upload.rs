@@ -0,0 +1,254 @@
+use std::{
+    io::SeekFrom,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    apis::execute_raw,
+    error::Error,
+    options::{TiktokOptions, apply_timeout},
+};
+use futures_util::TryStreamExt;
+use md5::{Digest, Md5};
+use reqwest::{
+    RequestBuilder,
+    header::{CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE},
+    multipart::Part,
+};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+
+const MIN_CHUNK_SIZE: u64 = 5 * 1024 * 1024;
+const MAX_CHUNK_SIZE: u64 = 64 * 1024 * 1024;
+const DEFAULT_CHUNK_SIZE: u64 = 10 * 1024 * 1024;
+const MAX_CHUNK_COUNT: u64 = 1000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkPlan {
+    pub video_size: u64,
+    pub chunk_size: u64,
+    pub total_chunk_count: u64,
+}
+
+impl ChunkPlan {
+    pub fn new(video_size: u64) -> Result<Self, Error> {
+        Self::with_chunk_size(video_size, DEFAULT_CHUNK_SIZE)
+    }
+
+    // 5MB未満は1チャンクで送る。端数は最後のチャンクに含める
+    pub fn with_chunk_size(video_size: u64, chunk_size: u64) -> Result<Self, Error> {
+        if video_size == 0 {
+            return Err(Error::Invalid("video_size 0".to_owned()));
+        }
+        if video_size < MIN_CHUNK_SIZE {
+            return Ok(Self {
+                video_size,
+                chunk_size: video_size,
+                total_chunk_count: 1,
+            });
+        }
+        let chunk_size = chunk_size
+            .max(video_size.div_ceil(MAX_CHUNK_COUNT))
+            .clamp(MIN_CHUNK_SIZE, MAX_CHUNK_SIZE)
+            .min(video_size);
+        Ok(Self {
+            video_size,
+            chunk_size,
+            total_chunk_count: video_size / chunk_size,
+        })
+    }
+
+    pub async fn from_file(video_file: &Path) -> Result<Self, Error> {
+        let metadata = tokio::fs::metadata(video_file).await?;
+        Self::new(metadata.len())
+    }
+
+    // [start, end)
+    pub fn range(&self, index: u64) -> (u64, u64) {
+        let start = index * self.chunk_size;
+        let end = if index + 1 >= self.total_chunk_count {
+            self.video_size
+        } else {
+            start + self.chunk_size
+        };
+        (start, end)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ChunkUploader {
+    options: Option<TiktokOptions>,
+    upload_url: String,
+    video_file: PathBuf,
+    plan: ChunkPlan,
+    next_chunk: u64,
+}
+
+impl ChunkUploader {
+    pub fn new(
+        upload_url: &str,
+        video_file: &Path,
+        plan: ChunkPlan,
+        options: Option<TiktokOptions>,
+    ) -> Self {
+        Self {
+            options,
+            upload_url: upload_url.to_owned(),
+            video_file: video_file.to_owned(),
+            plan,
+            next_chunk: 0,
+        }
+    }
+
+    // 失敗したチャンクから再開する
+    pub fn resume_from(mut self, chunk_index: u64) -> Self {
+        self.next_chunk = chunk_index;
+        self
+    }
+
+    pub fn next_chunk(&self) -> u64 {
+        self.next_chunk
+    }
+
+    pub fn plan(&self) -> &ChunkPlan {
+        &self.plan
+    }
+
+    fn build(&self, start: u64, end: u64) -> RequestBuilder {
+        let client = reqwest::Client::new()
+            .put(&self.upload_url)
+            .header(CONTENT_TYPE, video_content_type(&self.video_file))
+            .header(CONTENT_LENGTH, end - start)
+            .header(
+                CONTENT_RANGE,
+                format!("bytes {}-{}/{}", start, end - 1, self.plan.video_size),
+            )
+            .body(file_range_body(&self.video_file, start, end - start));
+        apply_timeout(client, &self.options)
+    }
+
+    // progressには送信済みバイト数と全体のバイト数を渡す
+    pub async fn upload(&mut self, mut progress: impl FnMut(u64, u64)) -> Result<(), Error> {
+        while self.next_chunk < self.plan.total_chunk_count {
+            let (start, end) = self.plan.range(self.next_chunk);
+            execute_raw(|| self.build(start, end), &self.options).await?;
+            self.next_chunk += 1;
+            progress(end, self.plan.video_size);
+        }
+        Ok(())
+    }
+}
+
+fn video_content_type(video_file: &Path) -> &'static str {
+    match video_file
+        .extension()
+        .map(|it| it.to_string_lossy().to_lowercase())
+        .as_deref()
+    {
+        Some("mov") => "video/quicktime",
+        Some("webm") => "video/webm",
+        _ => "video/mp4",
+    }
+}
+
+// ファイルを少しずつ読みながらMD5とサイズを計算する
+pub(crate) async fn file_md5(path: &Path) -> Result<(String, u64), Error> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Md5::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut size = 0;
+    loop {
+        let length = file.read(&mut buffer).await?;
+        if length == 0 {
+            break;
+        }
+        hasher.update(&buffer[..length]);
+        size += length as u64;
+    }
+    let signature = hasher
+        .finalize()
+        .iter()
+        .map(|it| format!("{:02x}", it))
+        .collect();
+    Ok((signature, size))
+}
+
+// リトライのたびにファイルを開き直すので、送信時まで読み込まない
+pub(crate) fn file_part(path: &Path, size: Option<u64>) -> Part {
+    let file_name = path
+        .file_name()
+        .map(|it| it.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let stream = futures_util::stream::once(tokio::fs::File::open(path.to_owned()))
+        .map_ok(ReaderStream::new)
+        .try_flatten();
+    let body = reqwest::Body::wrap_stream(stream);
+    let part = match size {
+        Some(size) => Part::stream_with_length(body, size),
+        None => Part::stream(body),
+    };
+    part.file_name(file_name)
+}
+
+fn file_range_body(path: &Path, start: u64, length: u64) -> reqwest::Body {
+    let path = path.to_owned();
+    let stream = futures_util::stream::once(async move {
+        let mut file = tokio::fs::File::open(path).await?;
+        file.seek(SeekFrom::Start(start)).await?;
+        Ok::<_, std::io::Error>(ReaderStream::new(file.take(length)))
+    })
+    .try_flatten();
+    reqwest::Body::wrap_stream(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // cargo test test_upload_file_md5 -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_upload_file_md5() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join("tiktok_business_test_upload_file_md5.txt");
+        std::fs::write(&path, "hello")?;
+        let (signature, size) = file_md5(&path).await?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(signature, "5d41402abc4b2a76b9719d911017c592");
+        assert_eq!(size, 5);
+        Ok(())
+    }
+
+    // cargo test test_upload_chunk_plan -- --nocapture --test-threads=1
+    #[test]
+    fn test_upload_chunk_plan() -> anyhow::Result<()> {
+        let mb = 1024 * 1024;
+        let plan = ChunkPlan::new(3 * mb)?;
+        assert_eq!(plan.total_chunk_count, 1);
+        assert_eq!(plan.range(0), (0, 3 * mb));
+
+        let plan = ChunkPlan::new(25 * mb + 7)?;
+        assert_eq!(plan.chunk_size, 10 * mb);
+        assert_eq!(plan.total_chunk_count, 2);
+        assert_eq!(plan.range(0), (0, 10 * mb));
+        assert_eq!(plan.range(1), (10 * mb, 25 * mb + 7));
+
+        let plan = ChunkPlan::with_chunk_size(8000 * mb, 5 * mb)?;
+        assert_eq!(plan.total_chunk_count, 1000);
+        assert_eq!(plan.chunk_size, 8 * mb);
+        assert_eq!(plan.range(999).1, 8000 * mb);
+
+        assert!(matches!(ChunkPlan::new(0), Err(Error::Invalid(_))));
+        assert!(ChunkPlan::with_chunk_size(0, 5 * mb).is_err());
+        Ok(())
+    }
+
+    // cargo test test_upload_chunk_plan_empty_file -- --nocapture --test-threads=1
+    #[tokio::test]
+    async fn test_upload_chunk_plan_empty_file() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join("tiktok_business_test_upload_empty.mp4");
+        std::fs::write(&path, b"")?;
+        let result = ChunkPlan::from_file(&path).await;
+        std::fs::remove_file(&path)?;
+        assert!(matches!(result, Err(Error::Invalid(_))));
+        Ok(())
+    }
+}
@@ -1,13 +1,13 @@
 use chrono::{Duration, prelude::*};
 use hmac::KeyInit;
 use serde::{Deserialize, Serialize};
-use strum::EnumString;
+use strum::{Display, EnumString};
 
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 type HmacSha256 = Hmac<Sha256>;
 
-#[derive(Debug, PartialEq, EnumString, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, EnumString, Display, Serialize, Deserialize, Clone)]
 pub enum EventType {
     #[serde(rename = "authorization.removed")]
     #[strum(serialize = "authorization.removed")]